@@ -41,6 +41,20 @@ export function deriveBattlePDA(gameId: bigint | number): PublicKey {
     return pda;
 }
 
+/**
+ * The battle a profile's activeGame points at, or null. Creating or joining a game
+ * takes it so the program can check that battle is over first.
+ */
+export function deriveActiveBattlePDA(activeGame: { gameId: BN; teamSize: number } | null): PublicKey | null {
+    if (!activeGame) return null;
+    const buf = activeGame.gameId.toArrayLike(Buffer, "le", 8);
+    const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from(activeGame.teamSize === 1 ? "battle" : "battle2v2"), buf],
+        PROGRAM_ID
+    );
+    return pda;
+}
+
 /** Derive the Clan PDA for a given name */
 export function deriveClanPDA(name: string): PublicKey {
     const [pda] = PublicKey.findProgramAddressSync(
@@ -65,7 +79,7 @@ export function deriveClanMemberPDA(clan: PublicKey, authority: PublicKey): Publ
  * useCounterProgram – primary hook for the Clash Royale on-chain game (game_core).
  *
  * Provides:
- *  - Base-layer operations: initializePlayer, createGame, joinGame, mintTrophies,
+ *  - Base-layer operations: initializePlayer, createGame, joinGame, settleBattle,
  *    setDeck, unlockCard, upgradeCard, createClan, joinClan, requestCards, donateCards,
  *    exportNft, exportResource, importResource
  *  - Ephemeral Rollup operations: delegateGame, deployTroop, endGame
@@ -281,24 +295,38 @@ export function useCounterProgram() {
     );
 
     /**
-     * Create a new game / battle room.
-     * Seeds: ["battle", gameId u64 LE] → BattleState PDA
+     * Create a new game / battle room under the id the game counter hands out next.
+     * Seeds: ["battle", game_counter.next_game_id u64 LE] → BattleState PDA.
+     * If another create takes that id first the seeds check fails; the counter is
+     * re-read and the next id tried.
+     * @returns The id the game was created under
      */
     const createGame = useCallback(
-        async (gameId: number | bigint): Promise<string> => {
+        async (): Promise<bigint> => {
             if (!program || !wallet.publicKey) throw new Error("Wallet not connected");
             setIsLoading(true);
             setError(null);
             try {
-                const gameBN = new BN(gameId.toString());
-                const tx = await program.methods
-                    .createGame(gameBN)
-                    .accounts({ playerOne: wallet.publicKey })
-                    .rpc();
-
-                const battleKey = deriveBattlePDA(gameId);
-                setBattlePubkey(battleKey);
-                return tx;
+                const [gameCounter] = PublicKey.findProgramAddressSync([Buffer.from("game_counter")], PROGRAM_ID);
+                const { activeGame } = await program.account.playerProfile.fetch(deriveProfilePDA(wallet.publicKey));
+                for (let attempt = 1; ; attempt++) {
+                    const gameId = BigInt((await program.account.gameCounter.fetch(gameCounter)).nextGameId.toString());
+                    try {
+                        await program.methods
+                            .createGame({ standard: {} }, [], false)
+                            .accounts({
+                                battle: deriveBattlePDA(gameId),
+                                playerOne: wallet.publicKey,
+                                activeBattle: deriveActiveBattlePDA(activeGame),
+                            } as any)
+                            .rpc();
+                        setBattlePubkey(deriveBattlePDA(gameId));
+                        return gameId;
+                    } catch (err) {
+                        const next = BigInt((await program.account.gameCounter.fetch(gameCounter)).nextGameId.toString());
+                        if (next === gameId || attempt >= 3) throw err;
+                    }
+                }
             } catch (err) {
                 const msg = err instanceof Error ? err.message : "Failed to create game";
                 setError(msg);
@@ -320,9 +348,10 @@ export function useCounterProgram() {
             setError(null);
             try {
                 const gameBN = new BN(gameId.toString());
+                const { activeGame } = await program.account.playerProfile.fetch(deriveProfilePDA(wallet.publicKey));
                 const tx = await program.methods
                     .joinGame(gameBN)
-                    .accounts({ playerTwo: wallet.publicKey })
+                    .accounts({ playerTwo: wallet.publicKey, activeBattle: deriveActiveBattlePDA(activeGame) } as any)
                     .rpc();
 
                 const battleKey = deriveBattlePDA(gameId);
@@ -462,26 +491,35 @@ export function useCounterProgram() {
     );
 
     /**
-     * Mint trophies after a game has been completed.
-     * Must be called from the base layer after end_game + undelegation.
-     * @param gameId      The game ID (u64)
-     * @param mint        The trophy-token mint account
-     * @param destination The player's associated token account for the trophy mint
+     * Settle a completed game: rating and trophy changes for both players and the
+     * token reward for the winner. Must be called from the base layer after
+     * end_game + undelegation.
+     * @param gameId The game ID (u64)
+     * @param mint   The reward-token mint account
+     * @param tokens Each player's token account for the mint, in player order
      */
-    const mintTrophies = useCallback(
-        async (gameId: number | bigint, mint: PublicKey, destination: PublicKey): Promise<string> => {
+    const settleBattle = useCallback(
+        async (gameId: number | bigint, mint: PublicKey, tokens: [PublicKey, PublicKey]): Promise<string> => {
             if (!program || !wallet.publicKey) throw new Error("Wallet not connected");
             setIsLoading(true);
             setError(null);
             try {
                 const gameBN = new BN(gameId.toString());
+                const { players } = await program.account.battleState.fetch(deriveBattlePDA(gameId));
                 const tx = await program.methods
-                    .mintTrophies(gameBN)
-                    .accounts({ signer: wallet.publicKey, mint, destination } as any)
+                    .settleBattle(gameBN)
+                    .accounts({
+                        caller: wallet.publicKey,
+                        mint,
+                        playerOneProfile: deriveProfilePDA(players[0]),
+                        playerTwoProfile: deriveProfilePDA(players[1]),
+                        playerOneTokens: tokens[0],
+                        playerTwoTokens: tokens[1],
+                    } as any)
                     .rpc();
                 return tx;
             } catch (err) {
-                const msg = err instanceof Error ? err.message : "Failed to mint trophies";
+                const msg = err instanceof Error ? err.message : "Failed to settle battle";
                 setError(msg);
                 throw err;
             } finally {
@@ -662,7 +700,7 @@ export function useCounterProgram() {
         setDeck,
         unlockCard,
         upgradeCard,
-        mintTrophies,
+        settleBattle,
 
         // Clan operations (base layer)
         createClan,
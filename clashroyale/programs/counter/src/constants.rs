@@ -1,7 +1,79 @@
-
-
 pub const MAX_ENTITIES: usize = 64;
 pub const MAX_INVENTORY: usize = 64;
 pub const DECK_SIZE: usize = 8;
+pub const HAND_SIZE: usize = 4;
+/// Slots after activation whose hash seeds the deck shuffle, so whoever activates a
+/// battle can't see the seed beforehand
+pub const SEED_SLOT_DELAY: u64 = 2;
+/// Padding in a card queue after the last filled deck slot
+pub const EMPTY_QUEUE_SLOT: u8 = u8::MAX;
+pub const MAX_CATALOG_CARDS: usize = 64;
+pub const MAX_UNITS_PER_CARD: usize = 8;
+pub const MAX_CARD_LEVEL: u8 = 13;
+/// Lobbies remembered on a profile for lookup by creator, oldest dropped first
+pub const MAX_CREATED_GAMES: usize = 8;
 pub const TOKEN_REWARD_AMOUNT: u64 = 50;
 pub const STARTER_CARDS: [u8; 4] = [1, 2, 3, 4];
+/// `winner_idx` value clients pass to signal a draw
+pub const DRAW_IDX: u8 = 255;
+/// How long an unfilled lobby waits before anyone may cancel it
+pub const LOBBY_TIMEOUT_SECONDS: i64 = 600;
+/// How long after completion a battle can be closed even with rewards unclaimed
+pub const CLOSE_GRACE_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+// Match clock defaults
+pub const REGULATION_SECONDS: u32 = 180;
+pub const OVERTIME_SECONDS: u32 = 120;
+/// Seconds a side may go without acting before the other can claim the win
+pub const AFK_TIMEOUT_SECONDS: u32 = 60;
+
+// Default arena grid: the web client's 18x30 playable tiles (tile coordinates, side 0
+// at negative y, the 2-row river on y -1..=0)
+pub const ARENA_MIN_X: i32 = -9;
+pub const ARENA_MAX_X: i32 = 8;
+pub const ARENA_MIN_Y: i32 = -15;
+pub const ARENA_MAX_Y: i32 = 14;
+pub const RIVER_MIN_Y: i32 = -1;
+pub const RIVER_MAX_Y: i32 = 0;
+pub const POCKET_DEPTH: i32 = 4;
+
+// Elixir (x100 fixed point: 100 = one elixir)
+pub const ELIXIR_UNIT: u64 = 100;
+pub const STARTING_ELIXIR: u64 = 500;
+pub const MAX_ELIXIR: u64 = 1000;
+pub const MS_PER_ELIXIR: u32 = 1000;
+pub const DOUBLE_ELIXIR_SECONDS: u32 = 60;
+
+// Simulation
+pub const TICK_SECONDS: i64 = 1;
+/// Target acquisition scans every entity for every entity, so a full board costs
+/// MAX_ENTITIES² distance checks per tick; keep the per-call batch small.
+pub const MAX_TICKS_PER_CALL: u64 = 4;
+pub const SIGHT_RANGE: i32 = 6;
+pub const TOWER_RANGE: i32 = 7;
+pub const KING_TOWER_DAMAGE: i32 = 70;
+pub const PRINCESS_TOWER_DAMAGE: i32 = 50;
+pub const KING_TOWER_HALF_SIZE: i32 = 2;
+pub const PRINCESS_TOWER_HALF_SIZE: i32 = 1;
+/// Share of a spell's damage applied to crown towers
+pub const SPELL_TOWER_DAMAGE_PERCENT: i64 = 30;
+/// Entity ids at or above this value refer to towers (`TOWER_TARGET_BASE + tower index`)
+pub const TOWER_TARGET_BASE: u32 = u32::MAX - 15;
+
+// Ratings
+/// Elo K-factor for MMR: an even match moves it by half of this
+pub const MMR_K_FACTOR: i64 = 32;
+/// Elo K-factor for trophies: an even match moves them by half of this
+pub const TROPHY_K_FACTOR: i64 = 60;
+
+// Seasons
+pub const SEASON_SECONDS: i64 = 28 * 24 * 60 * 60;
+/// Trophies above this are halved when a season ends
+pub const SEASON_RESET_THRESHOLD: u32 = 3000;
+
+// Matchmaking
+pub const MAX_QUEUE_ENTRIES: usize = 32;
+/// MMR gap accepted on entering the queue, how fast it widens and where it stops
+pub const MATCH_WINDOW_BASE: u32 = 50;
+pub const MATCH_WINDOW_PER_SECOND: u32 = 5;
+pub const MATCH_WINDOW_MAX: u32 = 500;
@@ -63,4 +63,64 @@ pub enum GameError {
     WinnerNotDetermined,
     #[msg("Trophies already minted for this game")]
     AlreadyMinted,
+    #[msg("Claimed result does not match the battle state")]
+    ResultMismatch,
+    #[msg("Invalid config")]
+    InvalidConfig,
+    #[msg("Card catalog is full")]
+    CatalogFull,
+    #[msg("Cannot deploy at that position")]
+    InvalidPlacement,
+    #[msg("Card is not in hand")]
+    CardNotInHand,
+    #[msg("Slot hashes unavailable")]
+    SlotHashesUnavailable,
+    #[msg("Hands are dealt on the first tick after the seed slot")]
+    HandsNotDealt,
+    #[msg("Invalid level rule")]
+    InvalidLevelRule,
+    #[msg("Lobby has not timed out yet")]
+    LobbyNotExpired,
+    #[msg("Rewards have not been settled yet")]
+    RewardsNotSettled,
+    #[msg("Player has left this game")]
+    PlayerLeft,
+    #[msg("No side has timed out")]
+    TimeoutNotReached,
+    #[msg("Battle already settled")]
+    AlreadySettled,
+    #[msg("Card is not available in your arena yet")]
+    ArenaLocked,
+    #[msg("Invalid trophy road milestone")]
+    InvalidMilestone,
+    #[msg("Not enough trophies for this milestone")]
+    MilestoneNotReached,
+    #[msg("Reward already claimed")]
+    RewardAlreadyClaimed,
+    #[msg("Not enough trophies to join this clan")]
+    NotEnoughTrophies,
+    #[msg("Season has not ended yet")]
+    SeasonNotOver,
+    #[msg("No season reward to claim")]
+    NoSeasonReward,
+    #[msg("Match queue is full")]
+    QueueFull,
+    #[msg("Already in the match queue")]
+    AlreadyQueued,
+    #[msg("Not in the match queue")]
+    NotQueued,
+    #[msg("No players can be matched yet")]
+    NoMatchFound,
+    #[msg("Invite list must fill every open seat without the creator or repeats")]
+    InvalidInviteList,
+    #[msg("Not invited to this lobby")]
+    NotInvited,
+    #[msg("Friendly battles are not settled")]
+    FriendlyBattle,
+    #[msg("The player's last battle is not over yet")]
+    StillInBattle,
+    #[msg("Remaining accounts must be the matched players' profiles in match order")]
+    MatchedProfileMismatch,
+    #[msg("Profile is already on the current layout")]
+    ProfileUpToDate,
 }
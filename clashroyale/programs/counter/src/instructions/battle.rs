@@ -8,28 +8,47 @@ use session_keys::{Session, SessionToken, session_auth_or, SessionError};
 use crate::state::*;
 use crate::errors::GameError;
 use crate::constants::*;
+use crate::sim;
+use crate::instructions::matchmaking::check_free;
 
 // ============================================================
 // Account Contexts
 // ============================================================
 
-/// Player 1 creates the game by providing a game_id from the frontend.
-/// Initializes BattleState PDA seeded by [b"battle", game_id].
+/// Player 1 creates the game under the next id from the GameCounter.
+/// Initializes BattleState PDA seeded by [b"battle", game_id] and records it on the
+/// creator's profile so clients can find it. Clients derive `battle` from
+/// `game_counter.next_game_id` as read before sending; if another create takes that id
+/// first the seeds check fails, and they re-read the counter and retry. As with `Enqueue`, the creator may not be
+/// queued, and `active_battle` must be passed while their profile points at a battle.
 #[derive(Accounts)]
-#[instruction(game_id: u64)]
 pub struct CreateGame<'info> {
+    #[account(mut, seeds = [b"game_counter"], bump = game_counter.bump)]
+    pub game_counter: Account<'info, GameCounter>,
+
     #[account(
         init,
         payer = player_one,
         space = 8 + BattleState::INIT_SPACE,
-        seeds = [b"battle", game_id.to_le_bytes().as_ref()],
+        seeds = [b"battle", game_counter.next_game_id.to_le_bytes().as_ref()],
         bump
     )]
     pub battle: Account<'info, BattleState>,
 
-    #[account(seeds = [b"player", player_one.key().as_ref()], bump)]
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(mut, seeds = [b"player", player_one.key().as_ref()], bump)]
     pub player_one_profile: Account<'info, PlayerProfile>,
 
+    #[account(seeds = [b"match_queue".as_ref(), &[1]], bump = queue_1v1.bump)]
+    pub queue_1v1: Account<'info, MatchQueue>,
+    #[account(seeds = [b"match_queue".as_ref(), &[2]], bump = queue_2v2.bump)]
+    pub queue_2v2: Account<'info, MatchQueue>,
+
+    /// CHECK: Matched against `profile.active_game` in the handler
+    pub active_battle: Option<UncheckedAccount<'info>>,
+
     #[account(mut)]
     pub player_one: Signer<'info>,
 
@@ -37,27 +56,41 @@ pub struct CreateGame<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(game_id: u64)]
 pub struct CreateGame2v2<'info> {
+    #[account(mut, seeds = [b"game_counter"], bump = game_counter.bump)]
+    pub game_counter: Account<'info, GameCounter>,
+
     #[account(
         init,
         payer = player_one,
         space = 8 + BattleState2v2::INIT_SPACE,
-        seeds = [b"battle2v2", game_id.to_le_bytes().as_ref()],
+        seeds = [b"battle2v2", game_counter.next_game_id.to_le_bytes().as_ref()],
         bump
     )]
     pub battle: Account<'info, BattleState2v2>,
 
-    #[account(seeds = [b"player", player_one.key().as_ref()], bump)]
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(mut, seeds = [b"player", player_one.key().as_ref()], bump)]
     pub player_one_profile: Account<'info, PlayerProfile>,
 
+    #[account(seeds = [b"match_queue".as_ref(), &[1]], bump = queue_1v1.bump)]
+    pub queue_1v1: Account<'info, MatchQueue>,
+    #[account(seeds = [b"match_queue".as_ref(), &[2]], bump = queue_2v2.bump)]
+    pub queue_2v2: Account<'info, MatchQueue>,
+
+    /// CHECK: Matched against `profile.active_game` in the handler
+    pub active_battle: Option<UncheckedAccount<'info>>,
+
     #[account(mut)]
     pub player_one: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
-/// Player 2 joins using the game_id. Game must be in Waiting status.
+/// Player 2 joins using the game_id. Game must be in Waiting status, and the player
+/// free in the same sense as for `CreateGame`.
 #[derive(Accounts)]
 #[instruction(game_id: u64)]
 pub struct JoinGame<'info> {
@@ -68,9 +101,17 @@ pub struct JoinGame<'info> {
     )]
     pub battle: Account<'info, BattleState>,
 
-    #[account(seeds = [b"player", player_two.key().as_ref()], bump)]
+    #[account(mut, seeds = [b"player", player_two.key().as_ref()], bump)]
     pub player_two_profile: Account<'info, PlayerProfile>,
 
+    #[account(seeds = [b"match_queue".as_ref(), &[1]], bump = queue_1v1.bump)]
+    pub queue_1v1: Account<'info, MatchQueue>,
+    #[account(seeds = [b"match_queue".as_ref(), &[2]], bump = queue_2v2.bump)]
+    pub queue_2v2: Account<'info, MatchQueue>,
+
+    /// CHECK: Matched against `profile.active_game` in the handler
+    pub active_battle: Option<UncheckedAccount<'info>>,
+
     #[account(mut)]
     pub player_two: Signer<'info>,
 }
@@ -85,13 +126,57 @@ pub struct JoinGame2v2<'info> {
     )]
     pub battle: Account<'info, BattleState2v2>,
 
-    #[account(seeds = [b"player", player.key().as_ref()], bump)]
+    #[account(mut, seeds = [b"player", player.key().as_ref()], bump)]
     pub player_profile: Account<'info, PlayerProfile>,
 
+    #[account(seeds = [b"match_queue".as_ref(), &[1]], bump = queue_1v1.bump)]
+    pub queue_1v1: Account<'info, MatchQueue>,
+    #[account(seeds = [b"match_queue".as_ref(), &[2]], bump = queue_2v2.bump)]
+    pub queue_2v2: Account<'info, MatchQueue>,
+
+    /// CHECK: Matched against `profile.active_game` in the handler
+    pub active_battle: Option<UncheckedAccount<'info>>,
+
     #[account(mut)]
     pub player: Signer<'info>,
 }
 
+/// Closes a lobby nobody has filled and refunds the rent to its payer. The creator can
+/// cancel at any time; anyone else only after LOBBY_TIMEOUT_SECONDS.
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct CancelGame<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"battle", game_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub battle: Account<'info, BattleState>,
+
+    #[account(mut, address = battle.payer @ GameError::InvalidPlayer)]
+    pub payer: SystemAccount<'info>,
+
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct CancelGame2v2<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"battle2v2", game_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub battle: Account<'info, BattleState2v2>,
+
+    #[account(mut, address = battle.payer @ GameError::InvalidPlayer)]
+    pub payer: SystemAccount<'info>,
+
+    pub caller: Signer<'info>,
+}
+
 /// Delegate the BattleState PDA to the Ephemeral Rollup.
 /// Either player can call this once the game is Active.
 #[delegate]
@@ -114,7 +199,8 @@ pub struct DelegateGame2v2<'info> {
     pub pda: AccountInfo<'info>,
 }
 
-/// Deploy a troop (runs on ER). Session key support for seamless gameplay.
+/// Deploy a troop (runs on ER). Session key support for seamless gameplay. Stats come from
+/// the deck snapshot taken at join time, so no profile is needed.
 #[derive(Accounts, Session)]
 #[instruction(game_id: u64)]
 pub struct DeployTroop<'info> {
@@ -128,10 +214,14 @@ pub struct DeployTroop<'info> {
     )]
     pub battle: Account<'info, BattleState>,
 
-    #[account(seeds = [b"player", player_profile.authority.key().as_ref()], bump)]
-    pub player_profile: Account<'info, PlayerProfile>,
+    /// CHECK: The player's wallet. Must be in the battle; the signer is either this
+    /// wallet or holds a session token for it.
+    pub player: UncheckedAccount<'info>,
 
-    #[session(signer = signer, authority = player_profile.authority)]
+    #[account(seeds = [b"catalog"], bump = catalog.bump)]
+    pub catalog: Account<'info, CardCatalog>,
+
+    #[session(signer = signer, authority = player.key())]
     pub session_token: Option<Account<'info, SessionToken>>,
 }
 
@@ -148,14 +238,115 @@ pub struct DeployTroop2v2<'info> {
     )]
     pub battle: Account<'info, BattleState2v2>,
 
-    #[account(seeds = [b"player", player_profile.authority.key().as_ref()], bump)]
-    pub player_profile: Account<'info, PlayerProfile>,
+    /// CHECK: The player's wallet. Must be in the battle; the signer is either this
+    /// wallet or holds a session token for it.
+    pub player: UncheckedAccount<'info>,
 
-    #[session(signer = signer, authority = player_profile.authority)]
+    #[account(seeds = [b"catalog"], bump = catalog.bump)]
+    pub catalog: Account<'info, CardCatalog>,
+
+    #[session(signer = signer, authority = player.key())]
+    pub session_token: Option<Account<'info, SessionToken>>,
+}
+
+/// Concede the battle (runs on ER). Session key support like deploy_troop.
+#[derive(Accounts, Session)]
+#[instruction(game_id: u64)]
+pub struct Surrender<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"battle", game_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub battle: Account<'info, BattleState>,
+
+    /// CHECK: The player's wallet. Must be in the battle; the signer is either this
+    /// wallet or holds a session token for it.
+    pub player: UncheckedAccount<'info>,
+
+    #[session(signer = signer, authority = player.key())]
     pub session_token: Option<Account<'info, SessionToken>>,
 }
 
-/// End game (runs on ER). Accepts the winner index from the frontend.
+#[derive(Accounts, Session)]
+#[instruction(game_id: u64)]
+pub struct Surrender2v2<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"battle2v2", game_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub battle: Account<'info, BattleState2v2>,
+
+    /// CHECK: The player's wallet. Must be in the battle; the signer is either this
+    /// wallet or holds a session token for it.
+    pub player: UncheckedAccount<'info>,
+
+    #[session(signer = signer, authority = player.key())]
+    pub session_token: Option<Account<'info, SessionToken>>,
+}
+
+/// Award the battle to the side still playing once the other has been idle for the
+/// battle's AFK timeout (runs on ER). Permissionless.
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct ClaimTimeout<'info> {
+    #[account(
+        mut,
+        seeds = [b"battle", game_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub battle: Account<'info, BattleState>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct ClaimTimeout2v2<'info> {
+    #[account(
+        mut,
+        seeds = [b"battle2v2", game_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub battle: Account<'info, BattleState2v2>,
+}
+
+/// Advance the battle simulation (runs on ER). Permissionless so a crank can drive it.
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct AdvanceTick<'info> {
+    #[account(
+        mut,
+        seeds = [b"battle", game_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub battle: Account<'info, BattleState>,
+    /// CHECK: SlotHashes sysvar, read for the deck shuffle seed
+    #[account(address = SLOT_HASHES_ID)]
+    pub recent_slothashes: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct AdvanceTick2v2<'info> {
+    #[account(
+        mut,
+        seeds = [b"battle2v2", game_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub battle: Account<'info, BattleState2v2>,
+    /// CHECK: SlotHashes sysvar, read for the deck shuffle seed
+    #[account(address = SLOT_HASHES_ID)]
+    pub recent_slothashes: UncheckedAccount<'info>,
+}
+
+/// End game (runs on ER) once the match is decided. The winner index from the frontend
+/// must match the result derived from the battle state.
 #[derive(Accounts, Session)]
 #[instruction(game_id: u64, winner_idx: u8)]
 pub struct EndGame<'info> {
@@ -225,10 +416,13 @@ pub struct CommitBattle2v2<'info> {
     pub battle: AccountInfo<'info>,
 }
 
-/// Mint trophies (runs on base layer after undelegation). Winner claims +50 trophies.
+/// Settles a completed battle in one call (base layer): rolls every participant's profile
+/// into the current season, applies the result to their MMR and trophies and mints the
+/// token reward to each winner. Anyone may call this, so a crank can finalize matches;
+/// profiles and token accounts are checked against the battle's players.
 #[derive(Accounts)]
 #[instruction(game_id: u64)]
-pub struct MintTrophies<'info> {
+pub struct SettleBattle<'info> {
     #[account(
         mut,
         seeds = [b"battle", game_id.to_le_bytes().as_ref()],
@@ -236,26 +430,33 @@ pub struct MintTrophies<'info> {
     )]
     pub battle: Account<'info, BattleState>,
 
-    #[account(mut, seeds = [b"player", signer.key().as_ref()], bump)]
-    pub profile: Account<'info, PlayerProfile>,
+    #[account(seeds = [b"season"], bump = season.bump)]
+    pub season: Account<'info, Season>,
+
+    #[account(mut, seeds = [b"player", battle.players[0].as_ref()], bump)]
+    pub player_one_profile: Box<Account<'info, PlayerProfile>>,
+    #[account(mut, seeds = [b"player", battle.players[1].as_ref()], bump)]
+    pub player_two_profile: Box<Account<'info, PlayerProfile>>,
+
+    #[account(mut, token::mint = mint, token::authority = battle.players[0])]
+    pub player_one_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(mut, token::mint = mint, token::authority = battle.players[1])]
+    pub player_two_tokens: Box<Account<'info, TokenAccount>>,
 
     #[account(mut)]
-    pub mint: Account<'info, Mint>,
-    #[account(mut)]
-    pub destination: Account<'info, TokenAccount>,
+    pub mint: Box<Account<'info, Mint>>,
     /// CHECK: Seeds check
     #[account(seeds = [b"mint_authority"], bump)]
     pub mint_authority: AccountInfo<'info>,
 
     pub token_program: Program<'info, Token>,
 
-    #[account(mut)]
-    pub signer: Signer<'info>,
+    pub caller: Signer<'info>,
 }
 
 #[derive(Accounts)]
 #[instruction(game_id: u64)]
-pub struct MintTrophies2v2<'info> {
+pub struct SettleBattle2v2<'info> {
     #[account(
         mut,
         seeds = [b"battle2v2", game_id.to_le_bytes().as_ref()],
@@ -263,95 +464,317 @@ pub struct MintTrophies2v2<'info> {
     )]
     pub battle: Account<'info, BattleState2v2>,
 
-    #[account(mut, seeds = [b"player", signer.key().as_ref()], bump)]
-    pub profile: Account<'info, PlayerProfile>,
+    #[account(seeds = [b"season"], bump = season.bump)]
+    pub season: Account<'info, Season>,
+
+    #[account(mut, seeds = [b"player", battle.players[0].as_ref()], bump)]
+    pub player_one_profile: Box<Account<'info, PlayerProfile>>,
+    #[account(mut, seeds = [b"player", battle.players[1].as_ref()], bump)]
+    pub player_two_profile: Box<Account<'info, PlayerProfile>>,
+    #[account(mut, seeds = [b"player", battle.players[2].as_ref()], bump)]
+    pub player_three_profile: Box<Account<'info, PlayerProfile>>,
+    #[account(mut, seeds = [b"player", battle.players[3].as_ref()], bump)]
+    pub player_four_profile: Box<Account<'info, PlayerProfile>>,
+
+    #[account(mut, token::mint = mint, token::authority = battle.players[0])]
+    pub player_one_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(mut, token::mint = mint, token::authority = battle.players[1])]
+    pub player_two_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(mut, token::mint = mint, token::authority = battle.players[2])]
+    pub player_three_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(mut, token::mint = mint, token::authority = battle.players[3])]
+    pub player_four_tokens: Box<Account<'info, TokenAccount>>,
 
     #[account(mut)]
-    pub mint: Account<'info, Mint>,
-    #[account(mut)]
-    pub destination: Account<'info, TokenAccount>,
+    pub mint: Box<Account<'info, Mint>>,
     /// CHECK: Seeds check
     #[account(seeds = [b"mint_authority"], bump)]
     pub mint_authority: AccountInfo<'info>,
 
     pub token_program: Program<'info, Token>,
 
-    #[account(mut)]
-    pub signer: Signer<'info>,
+    pub caller: Signer<'info>,
+}
+
+/// Closes a completed battle on the base layer and refunds the rent to its payer, once
+/// it is settled or CLOSE_GRACE_SECONDS have passed. Anyone may call this.
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct CloseBattle<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"battle", game_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub battle: Account<'info, BattleState>,
+
+    #[account(mut, address = battle.payer @ GameError::InvalidPlayer)]
+    pub payer: SystemAccount<'info>,
+
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct CloseBattle2v2<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"battle2v2", game_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub battle: Account<'info, BattleState2v2>,
+
+    #[account(mut, address = battle.payer @ GameError::InvalidPlayer)]
+    pub payer: SystemAccount<'info>,
+
+    pub caller: Signer<'info>,
 }
 
 // ============================================================
 // Instructions
 // ============================================================
 
-/// Player 1 creates a game lobby with a frontend-supplied game_id.
-pub fn create_game(ctx: Context<CreateGame>, game_id: u64) -> Result<()> {
-    let battle = &mut ctx.accounts.battle;
+pub(crate) const SLOT_HASHES_ID: Pubkey = pubkey!("SysvarS1otHashes111111111111111111111111111");
+
+/// Deck shuffle seed for a game from the hash of `seed_slot`, or of the first slot after
+/// it that wasn't skipped. None until that slot is in SlotHashes. Returns the slot the
+/// hash came from so players can verify it.
+fn shuffle_seed(slot_hashes_info: &AccountInfo, seed_slot: u64, game_id: u64) -> Result<Option<(u64, [u8; 32])>> {
+    let data = slot_hashes_info.try_borrow_data()?;
+    require!(data.len() >= 8, GameError::SlotHashesUnavailable);
+
+    Ok(sim::find_slot_hash(&data[8..], seed_slot).map(|(slot, hash)| (slot, sim::mix_seed(&hash, game_id))))
+}
+
+/// Fresh lobby state for `game_id` with no players seated. Clock, elixir schedule and
+/// arena are copied from the config so later config changes don't affect the match.
+pub(crate) fn init_battle(
+    battle: &mut BattleState,
+    config: &GameConfig,
+    game_id: u64,
+    payer: Pubkey,
+    level_rule: LevelRule,
+    now: i64,
+) {
     battle.game_id = game_id;
-    battle.players = [ctx.accounts.player_one.key(), Pubkey::default()];
+    battle.players = [Pubkey::default(); 2];
+    battle.payer = payer;
     battle.status = GameStatus::Waiting;
     battle.tick_count = 0;
-    battle.elixir = [500, 500];
+    battle.elixir = [STARTING_ELIXIR; 2];
     battle.winner = None;
-    battle.trophies_minted = false;
+    battle.settled = false;
     battle.entities = Vec::new();
-    battle.last_update_time = Clock::get()?.unix_timestamp;
-
-    battle.towers = [
-        Tower { health: 3000, x: 0,   y: -20, owner_idx: 0, is_king: true  },
-        Tower { health: 1500, x: -10, y: -15, owner_idx: 0, is_king: false },
-        Tower { health: 1500, x: 10,  y: -15, owner_idx: 0, is_king: false },
-        Tower { health: 3000, x: 0,   y: 20,  owner_idx: 1, is_king: true  },
-        Tower { health: 1500, x: -10, y: 15,  owner_idx: 1, is_king: false },
-        Tower { health: 1500, x: 10,  y: 15,  owner_idx: 1, is_king: false },
-    ];
-
-    msg!("Game {} created by {}", game_id, ctx.accounts.player_one.key());
-    Ok(())
+    battle.next_entity_id = 0;
+    battle.last_update_time = now;
+    battle.last_tick_time = now;
+    battle.created_at = now;
+    battle.started_at = 0;
+    battle.ended_at = 0;
+    battle.end_reason = EndReason::Played;
+    battle.last_action_at = [0; 2];
+    battle.regulation_seconds = config.regulation_seconds;
+    battle.overtime_seconds = config.overtime_seconds;
+    battle.afk_timeout_seconds = config.afk_timeout_seconds;
+    battle.elixir_schedule = config.elixir_schedule;
+    battle.elixir_carry = 0;
+    battle.arena = config.arena;
+    battle.level_rule = level_rule;
+    battle.invited = Vec::new();
+    battle.friendly = false;
+    battle.decks = [[DeckCard::default(); DECK_SIZE]; 2];
+    battle.card_queues = [[EMPTY_QUEUE_SLOT; DECK_SIZE]; 2];
+    battle.towers = sim::LAYOUT_1V1.initial_towers(&config.arena.into()).map(Tower::from);
 }
 
-/// Player 2 joins using the game_id. Transitions game to Active.
-pub fn join_game(ctx: Context<JoinGame>, _game_id: u64) -> Result<()> {
-    let battle = &mut ctx.accounts.battle;
+pub(crate) fn seat_player(battle: &mut BattleState, idx: usize, player: Pubkey, deck: [DeckCard; DECK_SIZE]) {
+    battle.players[idx] = player;
+    battle.decks[idx] = deck;
+    battle.card_queues[idx] = sim::init_queue(&deck.map(|c| c.card_id));
+}
 
-    require!(battle.status == GameStatus::Waiting, GameError::GameNotWaiting);
-    require!(battle.players[1] == Pubkey::default(), GameError::GameAlreadyFull);
-    require!(
-        battle.players[0] != ctx.accounts.player_two.key(),
-        GameError::InvalidPlayer
-    );
+/// Starts the match clock. Hands are dealt by the first `advance_tick` at or after
+/// `seed_slot`, whose hash nobody could see when the battle was activated.
+pub(crate) fn start_battle(battle: &mut BattleState, now: i64, slot: u64) {
+    battle.seed_slot = slot + SEED_SLOT_DELAY;
+    battle.hands_dealt = false;
 
-    battle.players[1] = ctx.accounts.player_two.key();
     battle.status = GameStatus::Active;
-    battle.last_update_time = Clock::get()?.unix_timestamp;
+    battle.last_update_time = now;
+    battle.last_tick_time = now;
+    battle.started_at = now;
+    battle.last_action_at = [now; 2];
+}
 
-    msg!("Player {} joined game {}", ctx.accounts.player_two.key(), battle.game_id);
+/// Shuffles every card queue once the seed slot's hash is available. No-op if the hands
+/// are already dealt or the seed slot hasn't been reached yet.
+fn deal_hands(battle: &mut BattleState, slot_hashes: &AccountInfo) -> Result<()> {
+    if battle.hands_dealt {
+        return Ok(());
+    }
+    let Some((seed_slot, seed)) = shuffle_seed(slot_hashes, battle.seed_slot, battle.game_id)? else {
+        return Ok(());
+    };
+    for (i, queue) in battle.card_queues.iter_mut().enumerate() {
+        sim::shuffle_queue(queue, sim::player_seed(&seed, i));
+    }
+    battle.shuffle_seed = seed;
+    battle.seed_slot = seed_slot;
+    battle.hands_dealt = true;
     Ok(())
 }
 
-/// Player 1 creates a game lobby with a frontend-supplied game_id for 2v2.
-pub fn create_game_2v2(ctx: Context<CreateGame2v2>, game_id: u64) -> Result<()> {
-    let battle = &mut ctx.accounts.battle;
+pub(crate) fn init_battle_2v2(
+    battle: &mut BattleState2v2,
+    config: &GameConfig,
+    game_id: u64,
+    payer: Pubkey,
+    level_rule: LevelRule,
+    now: i64,
+) {
     battle.game_id = game_id;
-    battle.players = [ctx.accounts.player_one.key(), Pubkey::default(), Pubkey::default(), Pubkey::default()];
+    battle.players = [Pubkey::default(); 4];
+    battle.payer = payer;
     battle.status = GameStatus::Waiting;
     battle.tick_count = 0;
-    battle.elixir = [500, 500, 500, 500];
+    battle.elixir = [STARTING_ELIXIR; 4];
     battle.winner = None;
-    battle.trophies_minted = [false; 4];
+    battle.settled = false;
+    battle.surrendered = [false; 4];
     battle.entities = Vec::new();
-    battle.last_update_time = Clock::get()?.unix_timestamp;
-
-    battle.towers = [
-        Tower { health: 4000, x: 0,   y: -20, owner_idx: 0, is_king: true  },
-        Tower { health: 2500, x: -10, y: -15, owner_idx: 0, is_king: false },
-        Tower { health: 2500, x: 10,  y: -15, owner_idx: 0, is_king: false },
-        Tower { health: 4000, x: 0,   y: 20,  owner_idx: 1, is_king: true  },
-        Tower { health: 2500, x: -10, y: 15,  owner_idx: 1, is_king: false },
-        Tower { health: 2500, x: 10,  y: 15,  owner_idx: 1, is_king: false },
-    ];
+    battle.next_entity_id = 0;
+    battle.last_update_time = now;
+    battle.last_tick_time = now;
+    battle.created_at = now;
+    battle.started_at = 0;
+    battle.ended_at = 0;
+    battle.end_reason = EndReason::Played;
+    battle.last_action_at = [0; 4];
+    battle.regulation_seconds = config.regulation_seconds;
+    battle.overtime_seconds = config.overtime_seconds;
+    battle.afk_timeout_seconds = config.afk_timeout_seconds;
+    battle.elixir_schedule = config.elixir_schedule;
+    battle.elixir_carry = 0;
+    battle.arena = config.arena;
+    battle.level_rule = level_rule;
+    battle.invited = Vec::new();
+    battle.friendly = false;
+    battle.decks = [[DeckCard::default(); DECK_SIZE]; 4];
+    battle.card_queues = [[EMPTY_QUEUE_SLOT; DECK_SIZE]; 4];
+    battle.towers = sim::LAYOUT_2V2.initial_towers(&config.arena.into()).map(Tower::from);
+}
+
+pub(crate) fn seat_player_2v2(battle: &mut BattleState2v2, idx: usize, player: Pubkey, deck: [DeckCard; DECK_SIZE]) {
+    battle.players[idx] = player;
+    battle.decks[idx] = deck;
+    battle.card_queues[idx] = sim::init_queue(&deck.map(|c| c.card_id));
+}
+
+pub(crate) fn start_battle_2v2(battle: &mut BattleState2v2, now: i64, slot: u64) {
+    battle.seed_slot = slot + SEED_SLOT_DELAY;
+    battle.hands_dealt = false;
+
+    battle.status = GameStatus::Active;
+    battle.last_update_time = now;
+    battle.last_tick_time = now;
+    battle.started_at = now;
+    battle.last_action_at = [now; 4];
+}
+
+/// Shuffles every card queue once the seed slot's hash is available. No-op if the hands
+/// are already dealt or the seed slot hasn't been reached yet.
+fn deal_hands_2v2(battle: &mut BattleState2v2, slot_hashes: &AccountInfo) -> Result<()> {
+    if battle.hands_dealt {
+        return Ok(());
+    }
+    let Some((seed_slot, seed)) = shuffle_seed(slot_hashes, battle.seed_slot, battle.game_id)? else {
+        return Ok(());
+    };
+    for (i, queue) in battle.card_queues.iter_mut().enumerate() {
+        sim::shuffle_queue(queue, sim::player_seed(&seed, i));
+    }
+    battle.shuffle_seed = seed;
+    battle.seed_slot = seed_slot;
+    battle.hands_dealt = true;
+    Ok(())
+}
+
+/// Player 1 creates a game lobby under the next counter id, with the level rule decks play
+/// under. A non-empty `invited` list makes it private; `friendly` skips settlement.
+pub fn create_game(ctx: Context<CreateGame>, level_rule: LevelRule, invited: Vec<Pubkey>, friendly: bool) -> Result<()> {
+    require!(level_rule.is_valid(), GameError::InvalidLevelRule);
+    require!(invites_valid(&invited, ctx.accounts.player_one.key(), 2), GameError::InvalidInviteList);
+
+    let now = Clock::get()?.unix_timestamp;
+    let player_one = ctx.accounts.player_one.key();
+    let queues = [&*ctx.accounts.queue_1v1, &*ctx.accounts.queue_2v2];
+    check_free(&mut ctx.accounts.player_one_profile, player_one, queues, ctx.accounts.active_battle.as_ref())?;
+
+    let game_id = ctx.accounts.game_counter.take_id();
+    let profile = &mut ctx.accounts.player_one_profile;
+    let deck = profile.deck_snapshot(level_rule);
+    profile.record_created_game(GameRef { game_id, team_size: 1 });
+    profile.active_game = Some(GameRef { game_id, team_size: 1 });
+
+    let battle = &mut ctx.accounts.battle;
+    init_battle(battle, &ctx.accounts.config, game_id, player_one, level_rule, now);
+    seat_player(battle, 0, player_one, deck);
+    battle.invited = invited;
+    battle.friendly = friendly;
+
+    msg!("Game {} created by {}", game_id, player_one);
+    Ok(())
+}
+
+/// Player 2 joins using the game_id. Transitions game to Active.
+pub fn join_game(ctx: Context<JoinGame>, _game_id: u64) -> Result<()> {
+    let battle = &mut ctx.accounts.battle;
+    let player_two = ctx.accounts.player_two.key();
+
+    require!(battle.status == GameStatus::Waiting, GameError::GameNotWaiting);
+    require!(battle.players[1] == Pubkey::default(), GameError::GameAlreadyFull);
+    require!(battle.players[0] != player_two, GameError::InvalidPlayer);
+    require!(admits(&battle.invited, player_two), GameError::NotInvited);
+
+    let queues = [&*ctx.accounts.queue_1v1, &*ctx.accounts.queue_2v2];
+    let profile = &mut ctx.accounts.player_two_profile;
+    check_free(profile, player_two, queues, ctx.accounts.active_battle.as_ref())?;
+    let deck = profile.deck_snapshot(battle.level_rule);
+    profile.active_game = Some(GameRef { game_id: battle.game_id, team_size: 1 });
+    seat_player(battle, 1, player_two, deck);
+    let clock = Clock::get()?;
+    start_battle(battle, clock.unix_timestamp, clock.slot);
+
+    msg!("Player {} joined game {}", player_two, battle.game_id);
+    Ok(())
+}
+
+/// Player 1 creates a 2v2 game lobby under the next counter id, with the level rule decks
+/// play under. A non-empty `invited` list makes it private; `friendly` skips settlement.
+pub fn create_game_2v2(ctx: Context<CreateGame2v2>, level_rule: LevelRule, invited: Vec<Pubkey>, friendly: bool) -> Result<()> {
+    require!(level_rule.is_valid(), GameError::InvalidLevelRule);
+    require!(invites_valid(&invited, ctx.accounts.player_one.key(), 4), GameError::InvalidInviteList);
+
+    let now = Clock::get()?.unix_timestamp;
+    let player_one = ctx.accounts.player_one.key();
+    let queues = [&*ctx.accounts.queue_1v1, &*ctx.accounts.queue_2v2];
+    check_free(&mut ctx.accounts.player_one_profile, player_one, queues, ctx.accounts.active_battle.as_ref())?;
+
+    let game_id = ctx.accounts.game_counter.take_id();
+    let profile = &mut ctx.accounts.player_one_profile;
+    let deck = profile.deck_snapshot(level_rule);
+    profile.record_created_game(GameRef { game_id, team_size: 2 });
+    profile.active_game = Some(GameRef { game_id, team_size: 2 });
+
+    let battle = &mut ctx.accounts.battle;
+    init_battle_2v2(battle, &ctx.accounts.config, game_id, player_one, level_rule, now);
+    seat_player_2v2(battle, 0, player_one, deck);
+    battle.invited = invited;
+    battle.friendly = friendly;
 
-    msg!("2v2 Game {} created by {}", game_id, ctx.accounts.player_one.key());
+    msg!("2v2 Game {} created by {}", game_id, player_one);
     Ok(())
 }
 
@@ -366,31 +789,23 @@ pub fn join_game_2v2(ctx: Context<JoinGame2v2>, _game_id: u64) -> Result<()> {
     for p in battle.players.iter() {
         require!(*p != player_key, GameError::InvalidPlayer);
     }
+    require!(admits(&battle.invited, player_key), GameError::NotInvited);
 
     // Find first empty slot
-    let mut joined = false;
-    for i in 1..4 {
-        if battle.players[i] == Pubkey::default() {
-            battle.players[i] = player_key;
-            joined = true;
-            break;
-        }
-    }
-
-    require!(joined, GameError::GameAlreadyFull);
+    let slot = (1..4)
+        .find(|&i| battle.players[i] == Pubkey::default())
+        .ok_or(GameError::GameAlreadyFull)?;
+    let queues = [&*ctx.accounts.queue_1v1, &*ctx.accounts.queue_2v2];
+    let profile = &mut ctx.accounts.player_profile;
+    check_free(profile, player_key, queues, ctx.accounts.active_battle.as_ref())?;
+    let deck = profile.deck_snapshot(battle.level_rule);
+    profile.active_game = Some(GameRef { game_id: battle.game_id, team_size: 2 });
+    seat_player_2v2(battle, slot, player_key, deck);
 
     // If all slots are filled, start the game
-    let mut all_joined = true;
-    for p in battle.players.iter() {
-        if *p == Pubkey::default() {
-            all_joined = false;
-            break;
-        }
-    }
-
-    if all_joined {
-        battle.status = GameStatus::Active;
-        battle.last_update_time = Clock::get()?.unix_timestamp;
+    if battle.players.iter().all(|p| *p != Pubkey::default()) {
+        let clock = Clock::get()?;
+        start_battle_2v2(battle, clock.unix_timestamp, clock.slot);
         msg!("2v2 Game {} is now Active!", battle.game_id);
     }
 
@@ -398,6 +813,34 @@ pub fn join_game_2v2(ctx: Context<JoinGame2v2>, _game_id: u64) -> Result<()> {
     Ok(())
 }
 
+/// Checks a lobby may be cancelled by `caller`. The account itself is closed by the context.
+fn check_cancel(status: &GameStatus, created_at: i64, creator: Pubkey, caller: Pubkey) -> Result<()> {
+    require!(*status == GameStatus::Waiting, GameError::GameNotWaiting);
+    if caller != creator {
+        let now = Clock::get()?.unix_timestamp;
+        require!(sim::lobby_expired(created_at, now), GameError::LobbyNotExpired);
+    }
+    Ok(())
+}
+
+/// Cancel an unfilled lobby, refunding its rent to the payer.
+pub fn cancel_game(ctx: Context<CancelGame>, _game_id: u64) -> Result<()> {
+    let battle = &ctx.accounts.battle;
+    check_cancel(&battle.status, battle.created_at, battle.players[0], ctx.accounts.caller.key())?;
+
+    msg!("Game {} cancelled by {}", battle.game_id, ctx.accounts.caller.key());
+    Ok(())
+}
+
+/// Cancel an unfilled 2v2 lobby, refunding its rent to the payer.
+pub fn cancel_game_2v2(ctx: Context<CancelGame2v2>, _game_id: u64) -> Result<()> {
+    let battle = &ctx.accounts.battle;
+    check_cancel(&battle.status, battle.created_at, battle.players[0], ctx.accounts.caller.key())?;
+
+    msg!("2v2 Game {} cancelled by {}", battle.game_id, ctx.accounts.caller.key());
+    Ok(())
+}
+
 /// Delegate the BattleState2v2 PDA to the ER. Any one player can call this.
 pub fn delegate_game_2v2(ctx: Context<DelegateGame2v2>, game_id: u64) -> Result<()> {
     ctx.accounts.delegate_pda(
@@ -424,155 +867,163 @@ pub fn delegate_game(ctx: Context<DelegateGame>, game_id: u64) -> Result<()> {
     Ok(())
 }
 
-/// Deploy a troop on ER. Validates player is in the game, deducts elixir, spawns entity.
-/// Checks for tower destruction: king tower = instant win, princess tower = tra
+/// Deploy a card on ER. Validates player is in the game, deducts elixir, spawns an entity
+/// (or resolves the spell immediately).
+/// Checks for tower destruction: king tower = instant win, princess tower = tracked for tiebreaks.
 #[session_auth_or(
-    ctx.accounts.player_profile.authority.key() == ctx.accounts.signer.key(),
+    ctx.accounts.player.key() == ctx.accounts.signer.key(),
     GameError::InvalidAuth
 )]
 pub fn deploy_troop(ctx: Context<DeployTroop>, _game_id: u64, card_idx: u8, x: i32, y: i32) -> Result<()> {
-    let battle = &mut ctx.accounts.battle;
-    let profile = &ctx.accounts.player_profile;
+    let battle: &mut BattleState = &mut ctx.accounts.battle;
 
     require!(battle.status == GameStatus::Active, GameError::GameNotActive);
 
-    // Elixir regeneration logic
     let now = Clock::get()?.unix_timestamp;
-    let elapsed = (now - battle.last_update_time).max(0) as u64;
-    if elapsed > 0 {
-        for i in 0..2 {
-            battle.elixir[i] = (battle.elixir[i] + elapsed * 100).min(1000);
-        }
-        battle.last_update_time = now;
-    }
 
-    let signer = ctx.accounts.player_profile.authority.key();
-    let player_idx = if battle.players[0] == signer {
-        0usize
-    } else if battle.players[1] == signer {
-        1usize
-    } else {
-        return err!(GameError::NotAPlayer);
-    };
+    // Time may have run out since the last action: settle the match instead of deploying.
+    let tally = battle.tally();
+    if sim::is_decided(&battle.clock(), now, &tally) {
+        battle.towers_destroyed = tally.towers_destroyed;
+        battle.damage_dealt = tally.damage_dealt;
+        battle.winner = tally.result();
+        battle.status = GameStatus::Completed;
+        battle.ended_at = now;
+        msg!("Game over on time. Winner: {:?}", battle.winner);
+        return Ok(());
+    }
 
-    if card_idx as usize >= 8 { return err!(GameError::InvalidCardIdx); }
-    let card_id = profile.deck[card_idx as usize];
-    if card_id == 0 { return err!(GameError::EmptyCardSlot); }
+    // Elixir regeneration logic
+    if now > battle.last_update_time {
+        let (schedule, clock, from) = (battle.elixir_schedule.into(), battle.clock(), battle.last_update_time);
+        sim::regen_elixir(&mut battle.elixir, &mut battle.elixir_carry, &schedule, &clock, from, now);
+        battle.last_update_time = now;
+    }
 
-    let config = profile.inventory.iter()
-        .find(|c| c.card_id == card_id)
-        .ok_or(GameError::CardNotOwned)?;
+    let signer = ctx.accounts.player.key();
+    let player_idx = battle.players.iter()
+        .position(|p| *p == signer)
+        .ok_or(GameError::NotAPlayer)?;
+    battle.last_action_at[player_idx] = now;
+    let side = sim::LAYOUT_1V1.side_of(player_idx);
 
-    let base_stats = get_card_stats(card_id).ok_or(GameError::InvalidCardId)?;
+    require!(battle.hands_dealt, GameError::HandsNotDealt);
+    if card_idx as usize >= DECK_SIZE { return err!(GameError::InvalidCardIdx); }
+    require!(
+        sim::play_from_hand(&mut battle.card_queues[player_idx], card_idx),
+        GameError::CardNotInHand
+    );
+    let card = battle.decks[player_idx][card_idx as usize];
+    if card.card_id == 0 { return err!(GameError::EmptyCardSlot); }
 
-    let multiplier = 100 + ((config.level as u64 - 1) * 10);
-    let scaled_health = (base_stats.health as u64 * multiplier / 100) as i32;
-    let scaled_damage = (base_stats.damage as u64 * multiplier / 100) as i32;
-    let scaled_cost = (base_stats.cost as u64) * 100;
+    let catalog_stats = ctx.accounts.catalog.get(card.card_id).ok_or(GameError::InvalidCardId)?;
+    let base_stats = sim::CardStats::from(catalog_stats);
+    let scaled = sim::scale_stats(&base_stats, card.level);
 
-    if battle.elixir[player_idx] < scaled_cost {
+    if !sim::spend_elixir(&mut battle.elixir[player_idx], scaled.cost) {
         return err!(GameError::NotEnoughElixir);
     }
-    battle.elixir[player_idx] -= scaled_cost;
 
-    if battle.entities.len() >= MAX_ENTITIES {
-        return err!(GameError::TooManyEntities);
+    let arena = sim::ArenaMap::from(battle.arena);
+    let mut towers = battle.sim_towers();
+    let mut entities = battle.sim_entities();
+
+    // Spells may land anywhere in the arena; units only in the player's zones.
+    let placeable = if base_stats.kind == sim::CardKind::Spell {
+        sim::in_bounds(&arena, x, y)
+    } else {
+        sim::can_deploy(&arena, &towers, side, x, y)
+    };
+    require!(placeable, GameError::InvalidPlacement);
+
+    if base_stats.kind == sim::CardKind::Spell {
+        sim::cast_spell(
+            &mut towers,
+            &mut entities,
+            side,
+            x,
+            y,
+            base_stats.range as i32,
+            scaled.damage,
+            &mut battle.towers_destroyed,
+            &mut battle.damage_dealt,
+        );
+    } else {
+        // Every unit of the card spawns, or none do.
+        let positions = sim::unit_positions(&base_stats, side, x, y);
+        require!(sim::can_deploy_all(&arena, &towers, side, &positions), GameError::InvalidPlacement);
+        sim::remove_dead(&mut entities);
+        if entities.len() + positions.len() > MAX_ENTITIES {
+            return err!(GameError::TooManyEntities);
+        }
+
+        for (ux, uy) in positions {
+            let new_id = battle.next_entity_id;
+            battle.next_entity_id += 1;
+            entities.push(sim::spawn_entity(&base_stats, &scaled, new_id, side, ux, uy));
+        }
     }
+    battle.store_sim(towers, entities);
 
-    let new_id = (battle.tick_count * 100) as u32 + battle.entities.len() as u32;
-
-    battle.entities.push(Entity {
-        id: new_id,
-        owner_idx: player_idx as u8,
-        card_id,
-        x,
-        y,
-        health: scaled_health,
-        damage: scaled_damage,
-        state: EntityState::Moving,
-        target_id: None,
-    });
-
-    battle.tick_count += 1;
-
-    // -------------------------------------------------------
-    // Tower destruction checks (winner determination)
-    //
-    // Tower layout (indices):
-    //   0 = P0 King Tower  (is_king: true)
-    //   1 = P0 Left Princess Tower
-    //   2 = P0 Right Princess Tower
-    //   3 = P1 King Tower  (is_king: true)
-    //   4 = P1 Left Princess Tower
-    //   5 = P1 Right Princess Tower
-    //
-    // Rule: King tower destroyed → instant win.
-    //       Princess tower destroyed → increment towers_destroyed for the attacker.
-    // -------------------------------------------------------
+    // Tower bookkeeping: king tower destroyed = instant win, princess towers and
+    // damage dealt are credited to the attacking side.
     if battle.winner.is_none() {
-        // Snapshot damage dealt to each side's towers
-        // Towers 0..2 belong to player 0; towers 3..5 belong to player 1
-        let mut p0_towers_lost: u8 = 0;
-        let mut p1_towers_lost: u8 = 0;
-
-        for (_i, tower) in battle.towers.iter().enumerate() {
-            if tower.health <= 0 {
-                if tower.owner_idx == 0 {
-                    // Player 0's tower was destroyed → Player 1 gets credit
-                    if tower.is_king {
-                        // Instant win for Player 1
-                        battle.winner = Some(1);
-                        battle.status = GameStatus::Completed;
-                        break;
-                    } else {
-                        p0_towers_lost += 1;
-                    }
-                } else {
-                    // Player 1's tower was destroyed → Player 0 gets credit
-                    if tower.is_king {
-                        // Instant win for Player 0
-                        battle.winner = Some(0);
-                        battle.status = GameStatus::Completed;
-                        break;
-                    } else {
-                        p1_towers_lost += 1;
-                    }
-                }
-            }
+        let tally = battle.tally();
+        battle.towers_destroyed = tally.towers_destroyed;
+        battle.damage_dealt = tally.damage_dealt;
+        if let Some(winner) = tally.winner {
+            battle.winner = Some(winner);
+            battle.status = GameStatus::Completed;
+            battle.ended_at = now;
         }
+    }
 
-        // Update towers_destroyed counters
-        // Player 0 destroyed p1_towers_lost of P1's princess towers
-        // Player 1 destroyed p0_towers_lost of P0's princess towers
-        battle.towers_destroyed[0] = p1_towers_lost;
-        battle.towers_destroyed[1] = p0_towers_lost;
-
-        // Update cumulative damage dealt to enemy towers
-        // Damage = initial HP - current HP for each tower
-        let p0_towers_initial: [i32; 3] = [3000, 1500, 1500]; // P0 towers at indices 0,1,2
-        let p1_towers_initial: [i32; 3] = [3000, 1500, 1500]; // P1 towers at indices 3,4,5
-
-        let mut damage_to_p1: u64 = 0;
-        for (i, &init) in p1_towers_initial.iter().enumerate() {
-            let current = battle.towers[3 + i].health;
-            let dmg = (init - current.max(0)) as u64;
-            damage_to_p1 += dmg;
-        }
-        let mut damage_to_p0: u64 = 0;
-        for (i, &init) in p0_towers_initial.iter().enumerate() {
-            let current = battle.towers[i].health;
-            let dmg = (init - current.max(0)) as u64;
-            damage_to_p0 += dmg;
+    Ok(())
+}
+
+/// Step the simulation on ER: one tick per elapsed `TICK_SECONDS`, capped at
+/// `MAX_TICKS_PER_CALL` so a stalled crank catches up over several calls.
+pub fn advance_tick(ctx: Context<AdvanceTick>, _game_id: u64) -> Result<()> {
+    let battle: &mut BattleState = &mut ctx.accounts.battle;
+
+    require!(battle.status == GameStatus::Active, GameError::GameNotActive);
+    deal_hands(battle, &ctx.accounts.recent_slothashes)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let due = ((now - battle.last_tick_time).max(0) / TICK_SECONDS) as u64;
+    let ticks = due.min(MAX_TICKS_PER_CALL);
+
+    let clock = battle.clock();
+    let mut towers = battle.sim_towers();
+    let mut entities = battle.sim_entities();
+    for _ in 0..ticks {
+        sim::step(
+            &mut towers,
+            &mut entities,
+            &mut battle.towers_destroyed,
+            &mut battle.damage_dealt,
+        );
+        sim::remove_dead(&mut entities);
+        battle.tick_count += 1;
+        battle.last_tick_time += TICK_SECONDS;
+
+        let tally = sim::tally_towers(&towers, &sim::LAYOUT_1V1);
+        if sim::is_decided(&clock, battle.last_tick_time, &tally) {
+            battle.winner = tally.result();
+            battle.status = GameStatus::Completed;
+            battle.ended_at = now;
+            msg!("Game over. Winner: {:?}", battle.winner);
+            break;
         }
-        battle.damage_dealt[0] = damage_to_p1; // P0 dealt damage to P1's towers
-        battle.damage_dealt[1] = damage_to_p0; // P1 dealt damage to P0's towers
     }
+    battle.store_sim(towers, entities);
 
     Ok(())
 }
 
 /// End game (runs on ER).
-/// winner_idx: 0 for P1, 1 for P2, 255 for Draw (None)
+/// winner_idx: 0 for P1, 1 for P2, 255 for Draw (None). The result is derived from the
+/// towers (king tower, towers destroyed, damage dealt) and the claim must agree with it.
 #[session_auth_or(
     ctx.accounts.player_profile.authority.key() == ctx.accounts.signer.key(),
     GameError::InvalidAuth
@@ -589,198 +1040,253 @@ pub fn end_game(ctx: Context<EndGame>, _game_id: u64, winner_idx: u8) -> Result<
         GameError::NotAPlayer
     );
 
-    if winner_idx == 255 {
-        battle.winner = None;
-    } else {
-        battle.winner = Some(winner_idx);
-    }
-    
-    battle.status = GameStatus::Completed;
-    msg!("Game ended. Winner: {:?}", battle.winner);
+    let tally = battle.tally();
+    let now = Clock::get()?.unix_timestamp;
+    require!(sim::is_decided(&battle.clock(), now, &tally), GameError::GameNotFinished);
 
-    Ok(())
-}
+    let result = tally.result();
+    let claimed = if winner_idx == DRAW_IDX { None } else { Some(winner_idx) };
+    require!(claimed == result, GameError::ResultMismatch);
 
-/// Commit and undelegate the battle account back to base layer
-pub fn commit_battle(ctx: Context<CommitBattle>, _game_id: u64) -> Result<()> {
-    commit_and_undelegate_accounts(
-        &ctx.accounts.payer,
-        vec![&ctx.accounts.battle.to_account_info()],
-        &ctx.accounts.magic_context,
-        &ctx.accounts.magic_program,
-    )?;
+    battle.towers_destroyed = tally.towers_destroyed;
+    battle.damage_dealt = tally.damage_dealt;
+    battle.winner = result;
+    battle.status = GameStatus::Completed;
+    battle.ended_at = now;
+    msg!("Game ended. Winner: {:?}", battle.winner);
 
     Ok(())
 }
 
-/// Winner calls mint_trophies on the base layer after undelegation. Mints 50 trophies.
-pub fn mint_trophies(ctx: Context<MintTrophies>, _game_id: u64) -> Result<()> {
+/// Concede: the other player wins immediately and the result is recorded as a forfeit.
+/// If the clock has already decided the match, that result stands instead.
+#[session_auth_or(
+    ctx.accounts.player.key() == ctx.accounts.signer.key(),
+    GameError::InvalidAuth
+)]
+pub fn surrender(ctx: Context<Surrender>, _game_id: u64) -> Result<()> {
     let battle = &mut ctx.accounts.battle;
-    let signer_key = ctx.accounts.signer.key();
 
-    // Check game is completed
-    require!(battle.status == GameStatus::Completed, GameError::GameNotFinished);
+    require!(battle.status == GameStatus::Active, GameError::GameNotActive);
 
-    // Check not already minted
-    require!(!battle.trophies_minted, GameError::AlreadyMinted);
+    let signer = ctx.accounts.player.key();
+    let player_idx = battle.players.iter()
+        .position(|p| *p == signer)
+        .ok_or(GameError::NotAPlayer)?;
 
-    // Verify caller is the winner
-    let winner_idx = battle.winner.ok_or(GameError::WinnerNotDetermined)?;
-    require!(
-        battle.players[winner_idx as usize] == signer_key,
-        GameError::NotWinner
-    );
+    let now = Clock::get()?.unix_timestamp;
+    let tally = battle.tally();
+    match sim::surrender_outcome(&sim::LAYOUT_1V1, &battle.clock(), now, &tally, &[false; 2], player_idx) {
+        sim::SurrenderOutcome::Decided(winner) => battle.winner = winner,
+        sim::SurrenderOutcome::Forfeit(winner) => {
+            battle.winner = Some(winner);
+            battle.end_reason = EndReason::Forfeit;
+        }
+        // Never for a one-player side
+        sim::SurrenderOutcome::Left => return Ok(()),
+    }
+    battle.towers_destroyed = tally.towers_destroyed;
+    battle.damage_dealt = tally.damage_dealt;
+    battle.status = GameStatus::Completed;
+    battle.ended_at = now;
 
-    // Mint SPL token (trophies)
-    let bump = ctx.bumps.mint_authority;
-    let seeds = &[b"mint_authority".as_ref(), &[bump]];
-    let signer_seeds = &[&seeds[..]];
+    msg!("Player {} surrendered. Winner: {:?}", signer, battle.winner);
+    Ok(())
+}
 
-    let cpi_accounts = MintTo {
-        mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.destination.to_account_info(),
-        authority: ctx.accounts.mint_authority.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+/// Complete the battle in favour of the active side once the other has gone idle.
+/// If the clock has already decided the match, that result stands instead.
+pub fn claim_timeout(ctx: Context<ClaimTimeout>, _game_id: u64) -> Result<()> {
+    let battle = &mut ctx.accounts.battle;
 
-    token::mint_to(cpi_ctx, TOKEN_REWARD_AMOUNT * 1_000_000)?;
+    require!(battle.status == GameStatus::Active, GameError::GameNotActive);
+
+    let now = Clock::get()?.unix_timestamp;
+    let tally = battle.tally();
+    if sim::is_decided(&battle.clock(), now, &tally) {
+        battle.winner = tally.result();
+    } else {
+        let idle = sim::idle_side(&sim::LAYOUT_1V1, &battle.last_action_at, now, battle.afk_timeout_seconds)
+            .ok_or(GameError::TimeoutNotReached)?;
+        battle.winner = Some(1 - idle);
+        battle.end_reason = EndReason::Timeout;
+    }
+    battle.towers_destroyed = tally.towers_destroyed;
+    battle.damage_dealt = tally.damage_dealt;
+    battle.status = GameStatus::Completed;
+    battle.ended_at = now;
 
-    // Update profile stats
-    ctx.accounts.profile.trophies += TOKEN_REWARD_AMOUNT as u32;
-    ctx.accounts.profile.mmr += 30;
+    msg!("Game {} ended on timeout. Winner: {:?}", battle.game_id, battle.winner);
+    Ok(())
+}
 
-    battle.trophies_minted = true;
+/// Commit and undelegate the battle account back to base layer
+pub fn commit_battle(ctx: Context<CommitBattle>, _game_id: u64) -> Result<()> {
+    commit_and_undelegate_accounts(
+        &ctx.accounts.payer,
+        vec![&ctx.accounts.battle.to_account_info()],
+        &ctx.accounts.magic_context,
+        &ctx.accounts.magic_program,
+    )?;
 
-    msg!("Minted {} trophies to winner {}", TOKEN_REWARD_AMOUNT, signer_key);
     Ok(())
 }
 
 /// Deploy a troop on ER for 2v2. Identifies team and player index.
 #[session_auth_or(
-    ctx.accounts.player_profile.authority.key() == ctx.accounts.signer.key(),
+    ctx.accounts.player.key() == ctx.accounts.signer.key(),
     GameError::InvalidAuth
 )]
 pub fn deploy_troop_2v2(ctx: Context<DeployTroop2v2>, _game_id: u64, card_idx: u8, x: i32, y: i32) -> Result<()> {
-    let battle = &mut ctx.accounts.battle;
-    let profile = &ctx.accounts.player_profile;
+    let battle: &mut BattleState2v2 = &mut ctx.accounts.battle;
 
     require!(battle.status == GameStatus::Active, GameError::GameNotActive);
 
-    // Elixir regeneration logic for 4 players
     let now = Clock::get()?.unix_timestamp;
-    let elapsed = (now - battle.last_update_time).max(0) as u64;
-    if elapsed > 0 {
-        for i in 0..4 {
-            battle.elixir[i] = (battle.elixir[i] + elapsed * 100).min(1000);
-        }
-        battle.last_update_time = now;
-    }
 
-    let signer = ctx.accounts.player_profile.authority.key();
-    let player_idx = if battle.players[0] == signer {
-        0usize
-    } else if battle.players[1] == signer {
-        1usize
-    } else if battle.players[2] == signer {
-        2usize
-    } else if battle.players[3] == signer {
-        3usize
-    } else {
-        return err!(GameError::NotAPlayer);
-    };
+    // Time may have run out since the last action: settle the match instead of deploying.
+    let tally = battle.tally();
+    if sim::is_decided(&battle.clock(), now, &tally) {
+        battle.towers_destroyed = tally.towers_destroyed;
+        battle.damage_dealt = tally.damage_dealt;
+        battle.winner = tally.result();
+        battle.status = GameStatus::Completed;
+        battle.ended_at = now;
+        msg!("2v2 Game over on time. Winner: {:?}", battle.winner);
+        return Ok(());
+    }
 
-    let team_idx = if player_idx < 2 { 0u8 } else { 1u8 };
+    // Elixir regeneration logic for 4 players
+    if now > battle.last_update_time {
+        let (schedule, clock, from) = (battle.elixir_schedule.into(), battle.clock(), battle.last_update_time);
+        sim::regen_elixir(&mut battle.elixir, &mut battle.elixir_carry, &schedule, &clock, from, now);
+        battle.last_update_time = now;
+    }
 
-    if card_idx as usize >= 8 { return err!(GameError::InvalidCardIdx); }
-    let card_id = profile.deck[card_idx as usize];
-    if card_id == 0 { return err!(GameError::EmptyCardSlot); }
+    let signer = ctx.accounts.player.key();
+    let player_idx = battle.players.iter()
+        .position(|p| *p == signer)
+        .ok_or(GameError::NotAPlayer)?;
+    require!(!battle.surrendered[player_idx], GameError::PlayerLeft);
+    battle.last_action_at[player_idx] = now;
 
-    let config = profile.inventory.iter()
-        .find(|c| c.card_id == card_id)
-        .ok_or(GameError::CardNotOwned)?;
+    let team_idx = sim::LAYOUT_2V2.side_of(player_idx);
 
-    let base_stats = get_card_stats(card_id).ok_or(GameError::InvalidCardId)?;
+    require!(battle.hands_dealt, GameError::HandsNotDealt);
+    if card_idx as usize >= DECK_SIZE { return err!(GameError::InvalidCardIdx); }
+    require!(
+        sim::play_from_hand(&mut battle.card_queues[player_idx], card_idx),
+        GameError::CardNotInHand
+    );
+    let card = battle.decks[player_idx][card_idx as usize];
+    if card.card_id == 0 { return err!(GameError::EmptyCardSlot); }
 
-    let multiplier = 100 + ((config.level as u64 - 1) * 10);
-    let scaled_health = (base_stats.health as u64 * multiplier / 100) as i32;
-    let scaled_damage = (base_stats.damage as u64 * multiplier / 100) as i32;
-    let scaled_cost = (base_stats.cost as u64) * 100;
+    let catalog_stats = ctx.accounts.catalog.get(card.card_id).ok_or(GameError::InvalidCardId)?;
+    let base_stats = sim::CardStats::from(catalog_stats);
+    let scaled = sim::scale_stats(&base_stats, card.level);
 
-    if battle.elixir[player_idx] < scaled_cost {
+    if !sim::spend_elixir(&mut battle.elixir[player_idx], scaled.cost) {
         return err!(GameError::NotEnoughElixir);
     }
-    battle.elixir[player_idx] -= scaled_cost;
-
-    if battle.entities.len() >= MAX_ENTITIES {
-        return err!(GameError::TooManyEntities);
-    }
 
-    let new_id = (battle.tick_count * 100) as u32 + battle.entities.len() as u32;
+    let arena = sim::ArenaMap::from(battle.arena);
+    let mut towers = battle.sim_towers();
+    let mut entities = battle.sim_entities();
 
-    battle.entities.push(Entity {
-        id: new_id,
-        owner_idx: team_idx, // Use team_idx for entity ownership
-        card_id,
-        x,
-        y,
-        health: scaled_health,
-        damage: scaled_damage,
-        state: EntityState::Moving,
-        target_id: None,
-    });
+    // Spells may land anywhere in the arena; units only in the player's zones.
+    let placeable = if base_stats.kind == sim::CardKind::Spell {
+        sim::in_bounds(&arena, x, y)
+    } else {
+        sim::can_deploy(&arena, &towers, team_idx, x, y)
+    };
+    require!(placeable, GameError::InvalidPlacement);
+
+    if base_stats.kind == sim::CardKind::Spell {
+        sim::cast_spell(
+            &mut towers,
+            &mut entities,
+            team_idx,
+            x,
+            y,
+            base_stats.range as i32,
+            scaled.damage,
+            &mut battle.towers_destroyed,
+            &mut battle.damage_dealt,
+        );
+    } else {
+        // Every unit of the card spawns, or none do.
+        let positions = sim::unit_positions(&base_stats, team_idx, x, y);
+        require!(sim::can_deploy_all(&arena, &towers, team_idx, &positions), GameError::InvalidPlacement);
+        sim::remove_dead(&mut entities);
+        if entities.len() + positions.len() > MAX_ENTITIES {
+            return err!(GameError::TooManyEntities);
+        }
 
-    battle.tick_count += 1;
+        for (ux, uy) in positions {
+            let new_id = battle.next_entity_id;
+            battle.next_entity_id += 1;
+            entities.push(sim::spawn_entity(&base_stats, &scaled, new_id, team_idx, ux, uy));
+        }
+    }
+    battle.store_sim(towers, entities);
 
-    // Tower destruction checks
+    // Tower bookkeeping: king tower destroyed = instant win, princess towers and
+    // damage dealt are credited to the attacking side.
     if battle.winner.is_none() {
-        let mut t0_lost: u8 = 0;
-        let mut t1_lost: u8 = 0;
-
-        for tower in battle.towers.iter() {
-            if tower.health <= 0 {
-                if tower.owner_idx == 0 {
-                    if tower.is_king {
-                        battle.winner = Some(1);
-                        battle.status = GameStatus::Completed;
-                        break;
-                    } else {
-                        t0_lost += 1;
-                    }
-                } else {
-                    if tower.is_king {
-                        battle.winner = Some(0);
-                        battle.status = GameStatus::Completed;
-                        break;
-                    } else {
-                        t1_lost += 1;
-                    }
-                }
-            }
+        let tally = battle.tally();
+        battle.towers_destroyed = tally.towers_destroyed;
+        battle.damage_dealt = tally.damage_dealt;
+        if let Some(winner) = tally.winner {
+            battle.winner = Some(winner);
+            battle.status = GameStatus::Completed;
+            battle.ended_at = now;
         }
-        battle.towers_destroyed[0] = t1_lost;
-        battle.towers_destroyed[1] = t0_lost;
+    }
 
-        // Cumulative damage
-        let t0_initial: [i32; 3] = [4000, 2500, 2500];
-        let t1_initial: [i32; 3] = [4000, 2500, 2500];
+    Ok(())
+}
 
-        let mut dmg_to_t1: u64 = 0;
-        for i in 0..3 {
-            dmg_to_t1 += (t1_initial[i] - battle.towers[3+i].health.max(0)) as u64;
-        }
-        let mut dmg_to_t0: u64 = 0;
-        for i in 0..3 {
-            dmg_to_t0 += (t0_initial[i] - battle.towers[i].health.max(0)) as u64;
+/// Step the 2v2 simulation on ER. Entities and towers are owned by team index.
+pub fn advance_tick_2v2(ctx: Context<AdvanceTick2v2>, _game_id: u64) -> Result<()> {
+    let battle: &mut BattleState2v2 = &mut ctx.accounts.battle;
+
+    require!(battle.status == GameStatus::Active, GameError::GameNotActive);
+    deal_hands_2v2(battle, &ctx.accounts.recent_slothashes)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let due = ((now - battle.last_tick_time).max(0) / TICK_SECONDS) as u64;
+    let ticks = due.min(MAX_TICKS_PER_CALL);
+
+    let clock = battle.clock();
+    let mut towers = battle.sim_towers();
+    let mut entities = battle.sim_entities();
+    for _ in 0..ticks {
+        sim::step(
+            &mut towers,
+            &mut entities,
+            &mut battle.towers_destroyed,
+            &mut battle.damage_dealt,
+        );
+        sim::remove_dead(&mut entities);
+        battle.tick_count += 1;
+        battle.last_tick_time += TICK_SECONDS;
+
+        let tally = sim::tally_towers(&towers, &sim::LAYOUT_2V2);
+        if sim::is_decided(&clock, battle.last_tick_time, &tally) {
+            battle.winner = tally.result();
+            battle.status = GameStatus::Completed;
+            battle.ended_at = now;
+            msg!("2v2 Game over. Winning team: {:?}", battle.winner);
+            break;
         }
-        battle.damage_dealt[0] = dmg_to_t1;
-        battle.damage_dealt[1] = dmg_to_t0;
     }
+    battle.store_sim(towers, entities);
 
     Ok(())
 }
 
-/// End 2v2 game.
+/// End 2v2 game. winner_idx is a team index (or 255 for a draw) and must match the
+/// result derived from the towers.
 #[session_auth_or(
     ctx.accounts.player_profile.authority.key() == ctx.accounts.signer.key(),
     GameError::InvalidAuth
@@ -800,14 +1306,91 @@ pub fn end_game_2v2(ctx: Context<EndGame2v2>, _game_id: u64, winner_idx: u8) ->
     }
     require!(is_player, GameError::NotAPlayer);
 
-    if winner_idx == 255 {
-        battle.winner = None;
+    let tally = battle.tally();
+    let now = Clock::get()?.unix_timestamp;
+    require!(sim::is_decided(&battle.clock(), now, &tally), GameError::GameNotFinished);
+
+    let result = tally.result();
+    let claimed = if winner_idx == DRAW_IDX { None } else { Some(winner_idx) };
+    require!(claimed == result, GameError::ResultMismatch);
+
+    battle.towers_destroyed = tally.towers_destroyed;
+    battle.damage_dealt = tally.damage_dealt;
+    battle.winner = result;
+    battle.status = GameStatus::Completed;
+    battle.ended_at = now;
+    msg!("2v2 Game ended. Winner: {:?}", battle.winner);
+    Ok(())
+}
+
+/// Concede a 2v2. The player leaves and can no longer deploy; once both teammates have
+/// surrendered the other team wins and the result is recorded as a forfeit.
+#[session_auth_or(
+    ctx.accounts.player.key() == ctx.accounts.signer.key(),
+    GameError::InvalidAuth
+)]
+pub fn surrender_2v2(ctx: Context<Surrender2v2>, _game_id: u64) -> Result<()> {
+    let battle = &mut ctx.accounts.battle;
+
+    require!(battle.status == GameStatus::Active, GameError::GameNotActive);
+
+    let signer = ctx.accounts.player.key();
+    let player_idx = battle.players.iter()
+        .position(|p| *p == signer)
+        .ok_or(GameError::NotAPlayer)?;
+    require!(!battle.surrendered[player_idx], GameError::PlayerLeft);
+
+    let now = Clock::get()?.unix_timestamp;
+    let tally = battle.tally();
+    let outcome = sim::surrender_outcome(&sim::LAYOUT_2V2, &battle.clock(), now, &tally, &battle.surrendered, player_idx);
+    match outcome {
+        sim::SurrenderOutcome::Decided(winner) => {
+            battle.winner = winner;
+            msg!("2v2 Game over on time. Winner: {:?}", battle.winner);
+        }
+        sim::SurrenderOutcome::Forfeit(winner) => {
+            battle.surrendered[player_idx] = true;
+            battle.winner = Some(winner);
+            battle.end_reason = EndReason::Forfeit;
+            msg!("Player {} left; team {} forfeited. Winning team: {}", signer, 1 - winner, winner);
+        }
+        sim::SurrenderOutcome::Left => {
+            battle.surrendered[player_idx] = true;
+            msg!("Player {} left 2v2 game {}", signer, battle.game_id);
+            return Ok(());
+        }
+    }
+    battle.towers_destroyed = tally.towers_destroyed;
+    battle.damage_dealt = tally.damage_dealt;
+    battle.status = GameStatus::Completed;
+    battle.ended_at = now;
+
+    Ok(())
+}
+
+/// Complete a 2v2 in favour of the active team once both players on the other have gone
+/// idle. If the clock has already decided the match, that result stands instead.
+pub fn claim_timeout_2v2(ctx: Context<ClaimTimeout2v2>, _game_id: u64) -> Result<()> {
+    let battle = &mut ctx.accounts.battle;
+
+    require!(battle.status == GameStatus::Active, GameError::GameNotActive);
+
+    let now = Clock::get()?.unix_timestamp;
+    let tally = battle.tally();
+    if sim::is_decided(&battle.clock(), now, &tally) {
+        battle.winner = tally.result();
     } else {
-        battle.winner = Some(winner_idx);
+        let idle = sim::idle_side(&sim::LAYOUT_2V2, &battle.last_action_at, now, battle.afk_timeout_seconds)
+            .ok_or(GameError::TimeoutNotReached)?;
+        battle.winner = Some(1 - idle);
+        battle.end_reason = EndReason::Timeout;
     }
-    
+    battle.towers_destroyed = tally.towers_destroyed;
+    battle.damage_dealt = tally.damage_dealt;
     battle.status = GameStatus::Completed;
-    msg!("2v2 Game ended. Winner: {:?}", battle.winner);
+    battle.ended_at = now;
+
+    msg!("2v2 Game {} ended on timeout. Winning team: {:?}", battle.game_id, battle.winner);
     Ok(())
 }
 
@@ -822,59 +1405,146 @@ pub fn commit_battle_2v2(ctx: Context<CommitBattle2v2>, _game_id: u64) -> Result
     Ok(())
 }
 
-/// Winner calls mint_trophies_2v2. Mints 50 trophies to any player on the winning team.
-pub fn mint_trophies_2v2(ctx: Context<MintTrophies2v2>, _game_id: u64) -> Result<()> {
-    let battle = &mut ctx.accounts.battle;
-    let signer_key = ctx.accounts.signer.key();
+/// Mints `amount` whole tokens as a reward, signed by the mint authority PDA.
+pub(crate) fn mint_reward<'info>(
+    mint: &Account<'info, Mint>,
+    to: &Account<'info, TokenAccount>,
+    mint_authority: &AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let seeds = &[b"mint_authority".as_ref(), &[bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = MintTo {
+        mint: mint.to_account_info(),
+        to: to.to_account_info(),
+        authority: mint_authority.clone(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer_seeds);
+
+    token::mint_to(cpi_ctx, amount * 1_000_000)
+}
+
+/// Settle a 1v1: Elo-style MMR and trophy changes for both players, and the token reward
+/// for the winner, scaled by the arena they played in. Draws mint nothing.
+pub fn settle_battle(ctx: Context<SettleBattle>, _game_id: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    let battle = &mut accounts.battle;
 
     require!(battle.status == GameStatus::Completed, GameError::GameNotFinished);
+    require!(!battle.settled, GameError::AlreadySettled);
+    require!(!battle.friendly, GameError::FriendlyBattle);
 
-    let winner_team = battle.winner.ok_or(GameError::WinnerNotDetermined)?;
-    
-    // Check if signer is in the winning team
-    let team_players = if winner_team == 0 {
-        [battle.players[0], battle.players[1]]
-    } else {
-        [battle.players[2], battle.players[3]]
-    };
+    let mut profiles = [&mut accounts.player_one_profile, &mut accounts.player_two_profile];
+    for profile in profiles.iter_mut() {
+        profile.roll_season(accounts.season.number);
+    }
+    let settlements = sim::settle(&sim::LAYOUT_1V1, battle.winner, &profiles.each_ref().map(|p| (p.mmr, p.trophies)));
+    for (profile, settlement) in profiles.into_iter().zip(&settlements) {
+        profile.mmr = settlement.mmr;
+        profile.set_trophies(settlement.trophies);
+        msg!("Player {}: mmr {}, trophies {}", profile.authority, profile.mmr, profile.trophies);
+    }
 
-    let mut is_winner = false;
-    let mut player_idx = 0;
-    for (i, p) in battle.players.iter().enumerate() {
-        if *p == signer_key {
-            for wp in team_players.iter() {
-                if *wp == signer_key {
-                    is_winner = true;
-                    player_idx = i;
-                    break;
-                }
-            }
+    let tokens = [&accounts.player_one_tokens, &accounts.player_two_tokens];
+    for (i, to) in tokens.into_iter().enumerate() {
+        let reward = settlements[i].reward;
+        if reward == 0 {
+            continue;
         }
+        mint_reward(
+            &accounts.mint,
+            to,
+            &accounts.mint_authority,
+            &accounts.token_program,
+            ctx.bumps.mint_authority,
+            reward,
+        )?;
+        msg!("Minted {} tokens to winner {}", reward, battle.players[i]);
     }
-    
-    require!(is_winner, GameError::NotWinner);
-    require!(!battle.trophies_minted[player_idx], GameError::AlreadyMinted);
 
-    // Mint SPL token
-    let bump = ctx.bumps.mint_authority;
-    let seeds = &[b"mint_authority".as_ref(), &[bump]];
-    let signer_seeds = &[&seeds[..]];
+    battle.settled = true;
+    Ok(())
+}
 
-    let cpi_accounts = MintTo {
-        mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.destination.to_account_info(),
-        authority: ctx.accounts.mint_authority.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+/// Settle a 2v2: MMR and trophy changes for all four players, rating each team by its
+/// average MMR, and the arena-scaled token reward for both players on the winning team.
+pub fn settle_battle_2v2(ctx: Context<SettleBattle2v2>, _game_id: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    let battle = &mut accounts.battle;
+
+    require!(battle.status == GameStatus::Completed, GameError::GameNotFinished);
+    require!(!battle.settled, GameError::AlreadySettled);
+    require!(!battle.friendly, GameError::FriendlyBattle);
+
+    let mut profiles = [
+        &mut accounts.player_one_profile,
+        &mut accounts.player_two_profile,
+        &mut accounts.player_three_profile,
+        &mut accounts.player_four_profile,
+    ];
+    for profile in profiles.iter_mut() {
+        profile.roll_season(accounts.season.number);
+    }
+    let settlements = sim::settle(&sim::LAYOUT_2V2, battle.winner, &profiles.each_ref().map(|p| (p.mmr, p.trophies)));
+    for (profile, settlement) in profiles.into_iter().zip(&settlements) {
+        profile.mmr = settlement.mmr;
+        profile.set_trophies(settlement.trophies);
+        msg!("Player {}: mmr {}, trophies {}", profile.authority, profile.mmr, profile.trophies);
+    }
 
-    token::mint_to(cpi_ctx, TOKEN_REWARD_AMOUNT * 1_000_000)?;
+    let tokens = [
+        &accounts.player_one_tokens,
+        &accounts.player_two_tokens,
+        &accounts.player_three_tokens,
+        &accounts.player_four_tokens,
+    ];
+    for (i, to) in tokens.into_iter().enumerate() {
+        let reward = settlements[i].reward;
+        if reward == 0 {
+            continue;
+        }
+        mint_reward(
+            &accounts.mint,
+            to,
+            &accounts.mint_authority,
+            &accounts.token_program,
+            ctx.bumps.mint_authority,
+            reward,
+        )?;
+        msg!("Minted {} tokens to winner {} in 2v2", reward, battle.players[i]);
+    }
 
-    ctx.accounts.profile.trophies += TOKEN_REWARD_AMOUNT as u32;
-    ctx.accounts.profile.mmr += 30;
+    battle.settled = true;
+    Ok(())
+}
+
+/// Checks a completed battle may be closed. The account itself is closed by the context.
+fn check_close(status: &GameStatus, ended_at: i64, settled: bool) -> Result<()> {
+    require!(*status == GameStatus::Completed, GameError::GameNotFinished);
+    if !settled {
+        let now = Clock::get()?.unix_timestamp;
+        require!(sim::close_grace_over(ended_at, now), GameError::RewardsNotSettled);
+    }
+    Ok(())
+}
+
+/// Close a completed battle, refunding its rent to the payer.
+pub fn close_battle(ctx: Context<CloseBattle>, _game_id: u64) -> Result<()> {
+    let battle = &ctx.accounts.battle;
+    check_close(&battle.status, battle.ended_at, battle.settled || battle.friendly)?;
+
+    msg!("Game {} closed by {}", battle.game_id, ctx.accounts.caller.key());
+    Ok(())
+}
 
-    battle.trophies_minted[player_idx] = true;
+/// Close a completed 2v2 battle, refunding its rent to the payer.
+pub fn close_battle_2v2(ctx: Context<CloseBattle2v2>, _game_id: u64) -> Result<()> {
+    let battle = &ctx.accounts.battle;
+    check_close(&battle.status, battle.ended_at, battle.settled || battle.friendly)?;
 
-    msg!("Minted {} trophies to winner {} in 2v2", TOKEN_REWARD_AMOUNT, signer_key);
+    msg!("2v2 Game {} closed by {}", battle.game_id, ctx.accounts.caller.key());
     Ok(())
 }
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GameError;
+use crate::constants::*;
+
+/// Creates the empty CardCatalog PDA. Only the config admin may do this.
+#[derive(Accounts)]
+pub struct InitializeCatalog<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + CardCatalog::INIT_SPACE,
+        seeds = [b"catalog"],
+        bump
+    )]
+    pub catalog: Account<'info, CardCatalog>,
+
+    #[account(seeds = [b"config"], bump = config.bump, has_one = admin @ GameError::Unauthorized)]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageCatalog<'info> {
+    #[account(mut, seeds = [b"catalog"], bump = catalog.bump)]
+    pub catalog: Account<'info, CardCatalog>,
+
+    #[account(seeds = [b"config"], bump = config.bump, has_one = admin @ GameError::Unauthorized)]
+    pub config: Account<'info, GameConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn initialize_catalog(ctx: Context<InitializeCatalog>) -> Result<()> {
+    let catalog = &mut ctx.accounts.catalog;
+    catalog.cards = Vec::new();
+    catalog.bump = ctx.bumps.catalog;
+    Ok(())
+}
+
+/// Add a card to the catalog or replace its stats.
+pub fn set_card_stats(ctx: Context<ManageCatalog>, stats: CardStats) -> Result<()> {
+    require!(stats.card_id != 0, GameError::InvalidCardId);
+    require!(stats.cost > 0 && stats.cost as u64 * ELIXIR_UNIT <= MAX_ELIXIR, GameError::InvalidConfig);
+    require!(stats.health >= 0 && stats.damage >= 0, GameError::InvalidConfig);
+    require!(
+        stats.unit_count >= 1 && stats.unit_count as usize <= MAX_UNITS_PER_CARD,
+        GameError::InvalidConfig
+    );
+    // A building without a lifetime would never decay and stand for the whole match.
+    require!(stats.kind != CardKind::Building || stats.lifetime > 0, GameError::InvalidConfig);
+
+    let catalog = &mut ctx.accounts.catalog;
+    let card_id = stats.card_id;
+    if let Some(existing) = catalog.cards.iter_mut().find(|c| c.card_id == card_id) {
+        *existing = stats;
+    } else {
+        require!(catalog.cards.len() < MAX_CATALOG_CARDS, GameError::CatalogFull);
+        catalog.cards.push(stats);
+    }
+
+    msg!("Card {} stats updated", card_id);
+    Ok(())
+}
+
+pub fn remove_card(ctx: Context<ManageCatalog>, card_id: u8) -> Result<()> {
+    let catalog = &mut ctx.accounts.catalog;
+    let index = catalog.cards.iter().position(|c| c.card_id == card_id).ok_or(GameError::InvalidCardId)?;
+    catalog.cards.remove(index);
+
+    msg!("Card {} removed from catalog", card_id);
+    Ok(())
+}
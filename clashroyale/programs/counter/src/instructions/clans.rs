@@ -44,6 +44,9 @@ pub struct JoinClan<'info> {
     )]
     pub clan_member: Account<'info, ClanMember>,
 
+    #[account(seeds = [b"player", authority.key().as_ref()], bump)]
+    pub profile: Account<'info, PlayerProfile>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -138,6 +141,7 @@ pub fn create_clan(ctx: Context<CreateClan>, name: String) -> Result<()> {
 pub fn join_clan(ctx: Context<JoinClan>) -> Result<()> {
     let clan = &mut ctx.accounts.clan;
     if clan.member_count >= 50 { return err!(GameError::ClanFull); }
+    require!(ctx.accounts.profile.trophies >= clan.min_trophies, GameError::NotEnoughTrophies);
     
     clan.member_count += 1;
 
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GameError;
+use crate::constants::*;
+use crate::sim;
+
+/// Creates the global GameConfig PDA. The payer becomes the admin.
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + GameConfig::INIT_SPACE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the GameCounter PDA that assigns battle ids. Only the config admin may do this.
+#[derive(Accounts)]
+pub struct InitializeGameCounter<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + GameCounter::INIT_SPACE,
+        seeds = [b"game_counter"],
+        bump
+    )]
+    pub game_counter: Account<'info, GameCounter>,
+
+    #[account(seeds = [b"config"], bump = config.bump, has_one = admin @ GameError::Unauthorized)]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ GameError::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.admin.key();
+    config.regulation_seconds = REGULATION_SECONDS;
+    config.overtime_seconds = OVERTIME_SECONDS;
+    config.afk_timeout_seconds = AFK_TIMEOUT_SECONDS;
+    config.elixir_schedule = ElixirSchedule {
+        ms_per_elixir: MS_PER_ELIXIR,
+        double_elixir_seconds: DOUBLE_ELIXIR_SECONDS,
+        double_multiplier: 2,
+        overtime_multiplier: 3,
+    };
+    config.arena = sim::DEFAULT_ARENA.into();
+    config.bump = ctx.bumps.config;
+
+    msg!("Config initialized, admin {}", config.admin);
+    Ok(())
+}
+
+/// Start handing out battle ids from `first_game_id`, which should be above any id
+/// already in use.
+pub fn initialize_game_counter(ctx: Context<InitializeGameCounter>, first_game_id: u64) -> Result<()> {
+    let counter = &mut ctx.accounts.game_counter;
+    counter.next_game_id = first_game_id;
+    counter.bump = ctx.bumps.game_counter;
+
+    msg!("Game counter initialized at {}", first_game_id);
+    Ok(())
+}
+
+/// Change match lengths. Only affects battles created afterwards.
+pub fn update_match_clock(ctx: Context<UpdateConfig>, regulation_seconds: u32, overtime_seconds: u32) -> Result<()> {
+    require!(regulation_seconds > 0, GameError::InvalidConfig);
+
+    let config = &mut ctx.accounts.config;
+    config.regulation_seconds = regulation_seconds;
+    config.overtime_seconds = overtime_seconds;
+
+    msg!("Match clock set to {}s + {}s overtime", regulation_seconds, overtime_seconds);
+    Ok(())
+}
+
+/// Change how long a side may idle before the other can claim the win. Only affects
+/// battles created afterwards.
+pub fn update_afk_timeout(ctx: Context<UpdateConfig>, afk_timeout_seconds: u32) -> Result<()> {
+    require!(afk_timeout_seconds > 0, GameError::InvalidConfig);
+
+    ctx.accounts.config.afk_timeout_seconds = afk_timeout_seconds;
+
+    msg!("AFK timeout set to {}s", afk_timeout_seconds);
+    Ok(())
+}
+
+/// Change the elixir phase schedule. Only affects battles created afterwards.
+pub fn update_elixir_schedule(ctx: Context<UpdateConfig>, schedule: ElixirSchedule) -> Result<()> {
+    require!(schedule.ms_per_elixir > 0, GameError::InvalidConfig);
+    require!(
+        schedule.double_multiplier >= 1 && schedule.overtime_multiplier >= 1,
+        GameError::InvalidConfig
+    );
+
+    ctx.accounts.config.elixir_schedule = schedule;
+
+    msg!("Elixir schedule updated: {:?}", schedule);
+    Ok(())
+}
+
+/// Change the arena grid and deploy zones. Only affects battles created afterwards.
+pub fn update_arena(ctx: Context<UpdateConfig>, arena: ArenaMap) -> Result<()> {
+    require!(sim::arena_is_valid(&arena.into()), GameError::InvalidConfig);
+
+    ctx.accounts.config.arena = arena;
+
+    msg!("Arena updated: {:?}", arena);
+    Ok(())
+}
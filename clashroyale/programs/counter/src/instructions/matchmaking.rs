@@ -0,0 +1,277 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GameError;
+use crate::constants::*;
+use crate::sim;
+use crate::instructions::battle::{
+    init_battle, init_battle_2v2, seat_player, seat_player_2v2, start_battle, start_battle_2v2,
+};
+
+/// Creates the match queue for `team_size` (1 for 1v1, 2 for 2v2). Only the config
+/// admin may do this.
+#[derive(Accounts)]
+#[instruction(team_size: u8)]
+pub struct InitializeMatchQueue<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + MatchQueue::INIT_SPACE,
+        seeds = [b"match_queue".as_ref(), &[team_size]],
+        bump
+    )]
+    pub queue: Account<'info, MatchQueue>,
+
+    #[account(seeds = [b"config"], bump = config.bump, has_one = admin @ GameError::Unauthorized)]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Joins a match queue. The player may not be waiting in the other queue, and while their
+/// profile points at a battle that battle must be passed as `active_battle`, so it can be
+/// checked to be over.
+#[derive(Accounts)]
+pub struct Enqueue<'info> {
+    #[account(mut, seeds = [b"match_queue".as_ref(), &[queue.team_size]], bump = queue.bump)]
+    pub queue: Account<'info, MatchQueue>,
+
+    #[account(seeds = [b"match_queue".as_ref(), &[3 - queue.team_size]], bump = other_queue.bump)]
+    pub other_queue: Account<'info, MatchQueue>,
+
+    #[account(mut, seeds = [b"player", player.key().as_ref()], bump)]
+    pub profile: Account<'info, PlayerProfile>,
+
+    /// CHECK: Matched against `profile.active_game` in the handler
+    pub active_battle: Option<UncheckedAccount<'info>>,
+
+    pub player: Signer<'info>,
+}
+
+/// Leave either match queue.
+#[derive(Accounts)]
+pub struct UpdateQueue<'info> {
+    #[account(mut, seeds = [b"match_queue".as_ref(), &[queue.team_size]], bump = queue.bump)]
+    pub queue: Account<'info, MatchQueue>,
+
+    #[account(seeds = [b"player", player.key().as_ref()], bump)]
+    pub profile: Account<'info, PlayerProfile>,
+
+    pub player: Signer<'info>,
+}
+
+/// Pops two matched players from the 1v1 queue into a new, already Active battle.
+/// Permissionless: the caller pays the battle's rent and gets it back on close. The
+/// matched players' profiles go in the remaining accounts, writable and in the order
+/// `sim::find_match` picks them.
+#[derive(Accounts)]
+pub struct PairPlayers<'info> {
+    #[account(mut, seeds = [b"match_queue".as_ref(), &[1]], bump = queue.bump)]
+    pub queue: Account<'info, MatchQueue>,
+
+    #[account(mut, seeds = [b"game_counter"], bump = game_counter.bump)]
+    pub game_counter: Account<'info, GameCounter>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BattleState::INIT_SPACE,
+        seeds = [b"battle", game_counter.next_game_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub battle: Account<'info, BattleState>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PairPlayers2v2<'info> {
+    #[account(mut, seeds = [b"match_queue".as_ref(), &[2]], bump = queue.bump)]
+    pub queue: Account<'info, MatchQueue>,
+
+    #[account(mut, seeds = [b"game_counter"], bump = game_counter.bump)]
+    pub game_counter: Account<'info, GameCounter>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BattleState2v2::INIT_SPACE,
+        seeds = [b"battle2v2", game_counter.next_game_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub battle: Account<'info, BattleState2v2>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_match_queue(ctx: Context<InitializeMatchQueue>, team_size: u8) -> Result<()> {
+    require!(team_size == 1 || team_size == 2, GameError::InvalidConfig);
+
+    let queue = &mut ctx.accounts.queue;
+    queue.team_size = team_size;
+    queue.entries = Vec::new();
+    queue.bump = ctx.bumps.queue;
+
+    msg!("Match queue for team size {} initialized", team_size);
+    Ok(())
+}
+
+/// Join the queue with the current MMR and deck.
+pub fn enqueue(ctx: Context<Enqueue>) -> Result<()> {
+    let player = ctx.accounts.player.key();
+    let queues = [&*ctx.accounts.queue, &*ctx.accounts.other_queue];
+    check_free(&mut ctx.accounts.profile, player, queues, ctx.accounts.active_battle.as_ref())?;
+
+    let queue = &mut ctx.accounts.queue;
+    let profile = &ctx.accounts.profile;
+    require!(queue.entries.len() < MAX_QUEUE_ENTRIES, GameError::QueueFull);
+
+    queue.entries.push(QueueEntry {
+        player,
+        mmr: profile.mmr,
+        deck: profile.deck_snapshot(LevelRule::Standard),
+        enqueued_at: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Player {} queued with mmr {}", player, profile.mmr);
+    Ok(())
+}
+
+pub fn leave_queue(ctx: Context<UpdateQueue>) -> Result<()> {
+    let queue = &mut ctx.accounts.queue;
+    let player = ctx.accounts.player.key();
+
+    let idx = queue.entries.iter()
+        .position(|e| e.player == player)
+        .ok_or(GameError::NotQueued)?;
+    queue.entries.remove(idx);
+
+    msg!("Player {} left the queue", player);
+    Ok(())
+}
+
+/// Refuses a player who is waiting in either match queue or still seated in a battle
+/// that isn't over, so nobody ends up in two matches at once. `battle` is the account
+/// `profile.active_game` points at, if any; once it is over the profile is released.
+pub(crate) fn check_free(
+    profile: &mut PlayerProfile,
+    player: Pubkey,
+    queues: [&MatchQueue; 2],
+    battle: Option<&UncheckedAccount>,
+) -> Result<()> {
+    let queued = |q: &MatchQueue| q.entries.iter().any(|e| e.player == player);
+    require!(!queues.into_iter().any(queued), GameError::AlreadyQueued);
+    release_active_game(profile, battle)?;
+    require!(profile.active_game.is_none(), GameError::StillInBattle);
+    Ok(())
+}
+
+/// Clears `profile.active_game` once that battle is over. `battle` must be its account;
+/// a battle that has since been closed counts as over.
+fn release_active_game(profile: &mut PlayerProfile, battle: Option<&UncheckedAccount>) -> Result<()> {
+    let Some(game) = profile.active_game else {
+        return Ok(());
+    };
+    let battle = battle.ok_or(GameError::StillInBattle)?;
+    let seed: &[u8] = if game.team_size == 1 { b"battle" } else { b"battle2v2" };
+    let (expected, _) = Pubkey::find_program_address(&[seed, game.game_id.to_le_bytes().as_ref()], &crate::ID);
+    require_keys_eq!(battle.key(), expected, GameError::StillInBattle);
+
+    if !battle.data_is_empty() {
+        require_keys_eq!(*battle.owner, crate::ID, GameError::StillInBattle);
+        let data = battle.try_borrow_data()?;
+        let status = if game.team_size == 1 {
+            BattleState::try_deserialize(&mut &data[..])?.status
+        } else {
+            BattleState2v2::try_deserialize(&mut &data[..])?.status
+        };
+        require!(status == GameStatus::Completed, GameError::StillInBattle);
+    }
+    profile.active_game = None;
+    Ok(())
+}
+
+/// Points each matched player's profile at their new battle. `profiles` are the
+/// instruction's remaining accounts, one per entry and in the same order.
+fn mark_active(profiles: &[AccountInfo], entries: &[QueueEntry], game: GameRef) -> Result<()> {
+    require!(profiles.len() == entries.len(), GameError::MatchedProfileMismatch);
+    for (info, entry) in profiles.iter().zip(entries) {
+        let (expected, _) = Pubkey::find_program_address(&[b"player", entry.player.as_ref()], &crate::ID);
+        require_keys_eq!(info.key(), expected, GameError::MatchedProfileMismatch);
+        require_keys_eq!(*info.owner, crate::ID, GameError::MatchedProfileMismatch);
+
+        let mut data = info.try_borrow_mut_data()?;
+        let mut profile = PlayerProfile::try_deserialize(&mut &data[..])?;
+        profile.active_game = Some(game);
+        profile.try_serialize(&mut &mut data[..])?;
+    }
+    Ok(())
+}
+
+/// Removes the matched entries from the queue and returns them in `group` order.
+fn take_entries(queue: &mut MatchQueue, group: &[usize]) -> Vec<QueueEntry> {
+    let taken = group.iter().map(|&i| queue.entries[i]).collect();
+    let mut sorted = group.to_vec();
+    sorted.sort_unstable();
+    for &i in sorted.iter().rev() {
+        queue.entries.remove(i);
+    }
+    taken
+}
+
+/// Pair the two best-fitting queued players and start their battle.
+pub fn pair_players(ctx: Context<PairPlayers>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let game_id = ctx.accounts.game_counter.take_id();
+    let queue = &mut ctx.accounts.queue;
+
+    let group = sim::find_match(&queue.sim_entries(), now, 2).ok_or(GameError::NoMatchFound)?;
+    let entries = take_entries(queue, &group);
+    mark_active(ctx.remaining_accounts, &entries, GameRef { game_id, team_size: 1 })?;
+
+    let battle = &mut ctx.accounts.battle;
+    init_battle(battle, &ctx.accounts.config, game_id, ctx.accounts.payer.key(), LevelRule::Standard, now);
+    for (i, entry) in entries.iter().enumerate() {
+        seat_player(battle, i, entry.player, entry.deck);
+    }
+    start_battle(battle, now, Clock::get()?.slot);
+
+    msg!("Paired {} and {} in game {}", entries[0].player, entries[1].player, game_id);
+    Ok(())
+}
+
+/// Match four queued players into balanced teams and start their 2v2 battle.
+pub fn pair_players_2v2(ctx: Context<PairPlayers2v2>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let game_id = ctx.accounts.game_counter.take_id();
+    let queue = &mut ctx.accounts.queue;
+
+    let group = sim::find_match(&queue.sim_entries(), now, 4).ok_or(GameError::NoMatchFound)?;
+    let entries = take_entries(queue, &group);
+    mark_active(ctx.remaining_accounts, &entries, GameRef { game_id, team_size: 2 })?;
+    let slots = sim::balance_teams([entries[0].mmr, entries[1].mmr, entries[2].mmr, entries[3].mmr]);
+
+    let battle = &mut ctx.accounts.battle;
+    init_battle_2v2(battle, &ctx.accounts.config, game_id, ctx.accounts.payer.key(), LevelRule::Standard, now);
+    for (i, &e) in slots.iter().enumerate() {
+        seat_player_2v2(battle, i, entries[e].player, entries[e].deck);
+    }
+    start_battle_2v2(battle, now, Clock::get()?.slot);
+
+    msg!("Matched four players in 2v2 game {}", game_id);
+    Ok(())
+}
@@ -2,8 +2,16 @@ pub mod player;
 pub mod battle;
 pub mod resources;
 pub mod clans;
+pub mod config;
+pub mod catalog;
+pub mod season;
+pub mod matchmaking;
 
 pub use player::*;
 pub use battle::*;
 pub use resources::*;
 pub use clans::*;
+pub use config::*;
+pub use catalog::*;
+pub use season::*;
+pub use matchmaking::*;
@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use crate::state::*;
 use crate::errors::GameError;
 use crate::constants::*;
+use crate::instructions::battle::mint_reward;
+use crate::sim;
 
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
@@ -34,6 +37,36 @@ pub struct ManageCard<'info> {
     pub authority: Signer<'info>,
 }
 
+/// Claim a trophy-road milestone once the player's trophies reach it.
+#[derive(Accounts)]
+pub struct ClaimTrophyReward<'info> {
+    #[account(mut, seeds = [b"player", authority.key().as_ref()], bump)]
+    pub profile: Account<'info, PlayerProfile>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    #[account(mut, token::mint = mint, token::authority = authority)]
+    pub user_token_account: Account<'info, TokenAccount>,
+    /// CHECK: Seeds check
+    #[account(seeds = [b"mint_authority"], bump)]
+    pub mint_authority: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub authority: Signer<'info>,
+}
+
+/// Move a profile created before the trophy road, season and lobby fields onto the
+/// current layout. The authority pays for the extra space.
+#[derive(Accounts)]
+pub struct MigrateProfile<'info> {
+    /// CHECK: Seeds check; still in the legacy layout, so it is read and rewritten by hand
+    #[account(mut, seeds = [b"player", authority.key().as_ref()], bump)]
+    pub profile: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
 pub fn initialize_player(ctx: Context<InitializePlayer>, username: String) -> Result<()> {
     let profile = &mut ctx.accounts.profile;
     profile.authority = ctx.accounts.authority.key();
@@ -48,6 +81,14 @@ pub fn initialize_player(ctx: Context<InitializePlayer>, username: String) -> Re
     
     profile.username = username;
     profile.trophies = 0;
+    profile.road_claimed = 0;
+    profile.season = 0;
+    profile.season_best_trophies = 0;
+    profile.best_season = 0;
+    profile.best_season_trophies = 0;
+    profile.pending_season_reward = 0;
+    profile.created_games = Vec::new();
+    profile.active_game = None;
 
     msg!("Player initialized: {}", ctx.accounts.authority.key());
     Ok(())
@@ -56,6 +97,8 @@ pub fn initialize_player(ctx: Context<InitializePlayer>, username: String) -> Re
 pub fn unlock_card(ctx: Context<ManageCard>, card_id: u8) -> Result<()> {
     let profile = &mut ctx.accounts.profile;
     let unlock_cost = 100;
+
+    require!(sim::can_unlock(profile.trophies, card_id), GameError::ArenaLocked);
     
     // Check if card is a starter card
     let is_starter = STARTER_CARDS.contains(&card_id);
@@ -100,7 +143,7 @@ pub fn upgrade_card(ctx: Context<ManageCard>, card_id: u8) -> Result<()> {
     let current_level = profile.inventory[card_idx].level;
     let current_amount = profile.inventory[card_idx].amount;
 
-    if current_level >= 13 { return err!(GameError::MaxLevelReached); }
+    if current_level >= MAX_CARD_LEVEL { return err!(GameError::MaxLevelReached); }
 
     let cards_needed = (current_level as u32) * 2;
     let token_cost = 50 * (current_level as u64).pow(2);
@@ -128,12 +171,62 @@ pub fn upgrade_card(ctx: Context<ManageCard>, card_id: u8) -> Result<()> {
 pub fn set_deck(ctx: Context<ManageCard>, new_deck: [u8; 8]) -> Result<()> {
     let profile = &mut ctx.accounts.profile;
     for &card_id in new_deck.iter() {
-        if card_id != 0 {
-            if !profile.inventory.iter().any(|c| c.card_id == card_id) {
-                return err!(GameError::CardNotOwned);
-            }
+        if card_id != 0 && !profile.inventory.iter().any(|c| c.card_id == card_id) {
+            return err!(GameError::CardNotOwned);
         }
     }
     profile.deck = new_deck;
     Ok(())
 }
+
+pub fn claim_road_reward(ctx: Context<ClaimTrophyReward>, milestone: u8) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    let reward = sim::TROPHY_ROAD.get(milestone as usize).ok_or(GameError::InvalidMilestone)?;
+    let bit = 1u64 << milestone;
+
+    require!(profile.trophies >= reward.trophies, GameError::MilestoneNotReached);
+    require!(profile.road_claimed & bit == 0, GameError::RewardAlreadyClaimed);
+
+    mint_reward(
+        &ctx.accounts.mint,
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.mint_authority,
+        &ctx.accounts.token_program,
+        ctx.bumps.mint_authority,
+        reward.tokens,
+    )?;
+    profile.road_claimed |= bit;
+
+    msg!("Claimed trophy road reward {} ({} tokens)", milestone, reward.tokens);
+    Ok(())
+}
+
+pub fn migrate_profile(ctx: Context<MigrateProfile>) -> Result<()> {
+    let info = ctx.accounts.profile.to_account_info();
+    require!(info.data_len() == 8 + LegacyPlayerProfile::INIT_SPACE, GameError::ProfileUpToDate);
+
+    let legacy = {
+        let data = info.try_borrow_data()?;
+        require!(data[..8] == *PlayerProfile::DISCRIMINATOR, GameError::ProfileUpToDate);
+        LegacyPlayerProfile::deserialize(&mut &data[8..])?
+    };
+
+    let space = 8 + PlayerProfile::INIT_SPACE;
+    let top_up = Rent::get()?.minimum_balance(space).saturating_sub(info.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer { from: ctx.accounts.authority.to_account_info(), to: info.clone() },
+            ),
+            top_up,
+        )?;
+    }
+    info.resize(space)?;
+
+    let profile = PlayerProfile::from(legacy);
+    profile.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    msg!("Profile migrated: {}", ctx.accounts.authority.key());
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::errors::GameError;
+use crate::constants::*;
+use crate::instructions::battle::mint_reward;
+
+/// Creates the Season PDA and starts season 1. Only the config admin may do this.
+#[derive(Accounts)]
+pub struct InitializeSeason<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Season::INIT_SPACE,
+        seeds = [b"season"],
+        bump
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(seeds = [b"config"], bump = config.bump, has_one = admin @ GameError::Unauthorized)]
+    pub config: Account<'info, GameConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Starts the next season once the current one has ended. Permissionless.
+#[derive(Accounts)]
+pub struct RollSeason<'info> {
+    #[account(mut, seeds = [b"season"], bump = season.bump)]
+    pub season: Account<'info, Season>,
+}
+
+/// Brings any player's profile into the current season. Permissionless so a crank can
+/// apply the soft reset without the player coming back online.
+#[derive(Accounts)]
+pub struct RollPlayerSeason<'info> {
+    #[account(seeds = [b"season"], bump = season.bump)]
+    pub season: Account<'info, Season>,
+
+    #[account(mut, seeds = [b"player", profile.authority.as_ref()], bump)]
+    pub profile: Account<'info, PlayerProfile>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimSeasonReward<'info> {
+    #[account(seeds = [b"season"], bump = season.bump)]
+    pub season: Account<'info, Season>,
+
+    #[account(mut, seeds = [b"player", authority.key().as_ref()], bump)]
+    pub profile: Account<'info, PlayerProfile>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    #[account(mut, token::mint = mint, token::authority = authority)]
+    pub user_token_account: Account<'info, TokenAccount>,
+    /// CHECK: Seeds check
+    #[account(seeds = [b"mint_authority"], bump)]
+    pub mint_authority: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub authority: Signer<'info>,
+}
+
+pub fn initialize_season(ctx: Context<InitializeSeason>) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    season.number = 1;
+    season.started_at = Clock::get()?.unix_timestamp;
+    season.ends_at = season.started_at + SEASON_SECONDS;
+    season.bump = ctx.bumps.season;
+
+    msg!("Season 1 started, ends at {}", season.ends_at);
+    Ok(())
+}
+
+pub fn roll_season(ctx: Context<RollSeason>) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= season.ends_at, GameError::SeasonNotOver);
+
+    season.number += 1;
+    season.started_at = now;
+    season.ends_at = now + SEASON_SECONDS;
+
+    msg!("Season {} started, ends at {}", season.number, season.ends_at);
+    Ok(())
+}
+
+pub fn roll_player_season(ctx: Context<RollPlayerSeason>) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    profile.roll_season(ctx.accounts.season.number);
+
+    msg!("Player {} in season {} with {} trophies", profile.authority, profile.season, profile.trophies);
+    Ok(())
+}
+
+/// Mint every end-of-season reward the player has banked, rolling the profile first.
+pub fn claim_season_reward(ctx: Context<ClaimSeasonReward>) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    profile.roll_season(ctx.accounts.season.number);
+
+    let reward = profile.pending_season_reward;
+    require!(reward > 0, GameError::NoSeasonReward);
+
+    mint_reward(
+        &ctx.accounts.mint,
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.mint_authority,
+        &ctx.accounts.token_program,
+        ctx.bumps.mint_authority,
+        reward,
+    )?;
+    profile.pending_season_reward = 0;
+
+    msg!("Claimed {} season reward tokens", reward);
+    Ok(())
+}
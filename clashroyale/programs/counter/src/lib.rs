@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
 use instructions::*;
+use state::{ArenaMap, CardStats, ElixirSchedule, LevelRule};
 
 pub mod constants;
 pub mod errors;
 pub mod instructions;
+pub mod sim;
 pub mod state;
 
 declare_id!("EYYaUKGcq2epXWsXk52P7dEXpDMZQpGdkSXVDypzDhYm");
@@ -30,15 +32,103 @@ pub mod game_core {
         instructions::player::set_deck(ctx, new_deck)
     }
 
+    pub fn claim_road_reward(ctx: Context<ClaimTrophyReward>, milestone: u8) -> Result<()> {
+        instructions::player::claim_road_reward(ctx, milestone)
+    }
+
+    pub fn migrate_profile(ctx: Context<MigrateProfile>) -> Result<()> {
+        instructions::player::migrate_profile(ctx)
+    }
+
+    // Config
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        instructions::config::initialize_config(ctx)
+    }
+
+    pub fn initialize_game_counter(ctx: Context<InitializeGameCounter>, first_game_id: u64) -> Result<()> {
+        instructions::config::initialize_game_counter(ctx, first_game_id)
+    }
+
+    pub fn update_match_clock(ctx: Context<UpdateConfig>, regulation_seconds: u32, overtime_seconds: u32) -> Result<()> {
+        instructions::config::update_match_clock(ctx, regulation_seconds, overtime_seconds)
+    }
+
+    pub fn update_afk_timeout(ctx: Context<UpdateConfig>, afk_timeout_seconds: u32) -> Result<()> {
+        instructions::config::update_afk_timeout(ctx, afk_timeout_seconds)
+    }
+
+    pub fn update_elixir_schedule(ctx: Context<UpdateConfig>, schedule: ElixirSchedule) -> Result<()> {
+        instructions::config::update_elixir_schedule(ctx, schedule)
+    }
+
+    pub fn update_arena(ctx: Context<UpdateConfig>, arena: ArenaMap) -> Result<()> {
+        instructions::config::update_arena(ctx, arena)
+    }
+
+    // Card Catalog
+    pub fn initialize_catalog(ctx: Context<InitializeCatalog>) -> Result<()> {
+        instructions::catalog::initialize_catalog(ctx)
+    }
+
+    pub fn set_card_stats(ctx: Context<ManageCatalog>, stats: CardStats) -> Result<()> {
+        instructions::catalog::set_card_stats(ctx, stats)
+    }
+
+    pub fn remove_card(ctx: Context<ManageCatalog>, card_id: u8) -> Result<()> {
+        instructions::catalog::remove_card(ctx, card_id)
+    }
+
+    // Seasons
+    pub fn initialize_season(ctx: Context<InitializeSeason>) -> Result<()> {
+        instructions::season::initialize_season(ctx)
+    }
+
+    pub fn roll_season(ctx: Context<RollSeason>) -> Result<()> {
+        instructions::season::roll_season(ctx)
+    }
+
+    pub fn roll_player_season(ctx: Context<RollPlayerSeason>) -> Result<()> {
+        instructions::season::roll_player_season(ctx)
+    }
+
+    pub fn claim_season_reward(ctx: Context<ClaimSeasonReward>) -> Result<()> {
+        instructions::season::claim_season_reward(ctx)
+    }
+
+    // Matchmaking
+    pub fn initialize_match_queue(ctx: Context<InitializeMatchQueue>, team_size: u8) -> Result<()> {
+        instructions::matchmaking::initialize_match_queue(ctx, team_size)
+    }
+
+    pub fn enqueue(ctx: Context<Enqueue>) -> Result<()> {
+        instructions::matchmaking::enqueue(ctx)
+    }
+
+    pub fn leave_queue(ctx: Context<UpdateQueue>) -> Result<()> {
+        instructions::matchmaking::leave_queue(ctx)
+    }
+
+    pub fn pair_players(ctx: Context<PairPlayers>) -> Result<()> {
+        instructions::matchmaking::pair_players(ctx)
+    }
+
+    pub fn pair_players_2v2(ctx: Context<PairPlayers2v2>) -> Result<()> {
+        instructions::matchmaking::pair_players_2v2(ctx)
+    }
+
     // Battle - Game Lobby
-    pub fn create_game(ctx: Context<CreateGame>, game_id: u64) -> Result<()> {
-        instructions::battle::create_game(ctx, game_id)
+    pub fn create_game(ctx: Context<CreateGame>, level_rule: LevelRule, invited: Vec<Pubkey>, friendly: bool) -> Result<()> {
+        instructions::battle::create_game(ctx, level_rule, invited, friendly)
     }
 
     pub fn join_game(ctx: Context<JoinGame>, game_id: u64) -> Result<()> {
         instructions::battle::join_game(ctx, game_id)
     }
 
+    pub fn cancel_game(ctx: Context<CancelGame>, game_id: u64) -> Result<()> {
+        instructions::battle::cancel_game(ctx, game_id)
+    }
+
     // Battle - Delegation
     pub fn delegate_game(ctx: Context<DelegateGame>, game_id: u64) -> Result<()> {
         instructions::battle::delegate_game(ctx, game_id)
@@ -49,30 +139,50 @@ pub mod game_core {
         instructions::battle::deploy_troop(ctx, game_id, card_idx, x, y)
     }
 
+    pub fn advance_tick(ctx: Context<AdvanceTick>, game_id: u64) -> Result<()> {
+        instructions::battle::advance_tick(ctx, game_id)
+    }
+
     // Battle - End Game (on ER)
     pub fn end_game(ctx: Context<EndGame>, game_id: u64, winner_idx: u8) -> Result<()> {
         instructions::battle::end_game(ctx, game_id, winner_idx)
     }
 
+    pub fn surrender(ctx: Context<Surrender>, game_id: u64) -> Result<()> {
+        instructions::battle::surrender(ctx, game_id)
+    }
+
+    pub fn claim_timeout(ctx: Context<ClaimTimeout>, game_id: u64) -> Result<()> {
+        instructions::battle::claim_timeout(ctx, game_id)
+    }
+
     // Battle - Commit to Base Layer
     pub fn commit_battle(ctx: Context<CommitBattle>, game_id: u64) -> Result<()> {
         instructions::battle::commit_battle(ctx, game_id)
     }
 
     // Battle - Rewards (on base layer)
-    pub fn mint_trophies(ctx: Context<MintTrophies>, game_id: u64) -> Result<()> {
-        instructions::battle::mint_trophies(ctx, game_id)
+    pub fn settle_battle(ctx: Context<SettleBattle>, game_id: u64) -> Result<()> {
+        instructions::battle::settle_battle(ctx, game_id)
+    }
+
+    pub fn close_battle(ctx: Context<CloseBattle>, game_id: u64) -> Result<()> {
+        instructions::battle::close_battle(ctx, game_id)
     }
 
     // --- 2v2 Battle ---
-    pub fn create_game_2v2(ctx: Context<CreateGame2v2>, game_id: u64) -> Result<()> {
-        instructions::battle::create_game_2v2(ctx, game_id)
+    pub fn create_game_2v2(ctx: Context<CreateGame2v2>, level_rule: LevelRule, invited: Vec<Pubkey>, friendly: bool) -> Result<()> {
+        instructions::battle::create_game_2v2(ctx, level_rule, invited, friendly)
     }
 
     pub fn join_game_2v2(ctx: Context<JoinGame2v2>, game_id: u64) -> Result<()> {
         instructions::battle::join_game_2v2(ctx, game_id)
     }
 
+    pub fn cancel_game_2v2(ctx: Context<CancelGame2v2>, game_id: u64) -> Result<()> {
+        instructions::battle::cancel_game_2v2(ctx, game_id)
+    }
+
     pub fn delegate_game_2v2(ctx: Context<DelegateGame2v2>, game_id: u64) -> Result<()> {
         instructions::battle::delegate_game_2v2(ctx, game_id)
     }
@@ -81,16 +191,32 @@ pub mod game_core {
         instructions::battle::deploy_troop_2v2(ctx, game_id, card_idx, x, y)
     }
 
+    pub fn advance_tick_2v2(ctx: Context<AdvanceTick2v2>, game_id: u64) -> Result<()> {
+        instructions::battle::advance_tick_2v2(ctx, game_id)
+    }
+
     pub fn end_game_2v2(ctx: Context<EndGame2v2>, game_id: u64, winner_idx: u8) -> Result<()> {
         instructions::battle::end_game_2v2(ctx, game_id, winner_idx)
     }
 
+    pub fn surrender_2v2(ctx: Context<Surrender2v2>, game_id: u64) -> Result<()> {
+        instructions::battle::surrender_2v2(ctx, game_id)
+    }
+
+    pub fn claim_timeout_2v2(ctx: Context<ClaimTimeout2v2>, game_id: u64) -> Result<()> {
+        instructions::battle::claim_timeout_2v2(ctx, game_id)
+    }
+
     pub fn commit_battle_2v2(ctx: Context<CommitBattle2v2>, game_id: u64) -> Result<()> {
         instructions::battle::commit_battle_2v2(ctx, game_id)
     }
 
-    pub fn mint_trophies_2v2(ctx: Context<MintTrophies2v2>, game_id: u64) -> Result<()> {
-        instructions::battle::mint_trophies_2v2(ctx, game_id)
+    pub fn settle_battle_2v2(ctx: Context<SettleBattle2v2>, game_id: u64) -> Result<()> {
+        instructions::battle::settle_battle_2v2(ctx, game_id)
+    }
+
+    pub fn close_battle_2v2(ctx: Context<CloseBattle2v2>, game_id: u64) -> Result<()> {
+        instructions::battle::close_battle_2v2(ctx, game_id)
     }
 
     // Resources
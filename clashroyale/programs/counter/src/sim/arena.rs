@@ -0,0 +1,106 @@
+use crate::constants::*;
+
+use super::{distance, tower_half_size, ArenaMap, Tower, TowerSite};
+
+/// The arena new configs start with: the web client's 18x30 grid with a 2-row river.
+///
+/// A client tile (`col`, `row`) in ArenaConfig.ts is program tile (`col - 12`, `18 - row`):
+/// playable cols 3..=20 become x -9..=8, rows 4..=33 become y 14..=-15, and the river
+/// rows 18 and 19 become y 0 and -1. The player at the bottom of the client's screen is
+/// side 0.
+///
+/// Towers only approximate the client. It draws kings on a 4x4 sand grid centred on half
+/// tiles (gridCol 11.5, gridRow 32 and 6, i.e. x -0.5, y -14 and 12) and its two kings
+/// are not mirror images. Here every tower is a square of odd width around a whole tile
+/// (KING_TOWER_HALF_SIZE, PRINCESS_TOWER_HALF_SIZE), so kings sit at x 0 and side 0's is
+/// moved to y -13, the mirror of side 1's, which keeps its footprint on the grid.
+/// Princess towers match the client exactly.
+pub const DEFAULT_ARENA: ArenaMap = ArenaMap {
+    min_x: ARENA_MIN_X,
+    max_x: ARENA_MAX_X,
+    min_y: ARENA_MIN_Y,
+    max_y: ARENA_MAX_Y,
+    river_min_y: RIVER_MIN_Y,
+    river_max_y: RIVER_MAX_Y,
+    pocket_depth: POCKET_DEPTH,
+    towers: [
+        TowerSite { x: 0,  y: -13 },
+        TowerSite { x: -6, y: -10 },
+        TowerSite { x: 5,  y: -10 },
+        TowerSite { x: 0,  y: 12 },
+        TowerSite { x: -6, y: 9 },
+        TowerSite { x: 5,  y: 9 },
+    ],
+};
+
+/// Whether `arena` is a usable grid: non-empty, with the river strictly inside it, and
+/// every tower's footprint within bounds and clear of the river.
+pub fn arena_is_valid(arena: &ArenaMap) -> bool {
+    let grid_ok = arena.min_x < arena.max_x
+        && arena.min_y < arena.max_y
+        && arena.river_min_y > arena.min_y
+        && arena.river_max_y < arena.max_y
+        && arena.river_min_y <= arena.river_max_y
+        && arena.pocket_depth >= 0;
+
+    grid_ok
+        && arena.towers.iter().enumerate().all(|(i, site)| {
+            let half = if i % 3 == 0 { KING_TOWER_HALF_SIZE } else { PRINCESS_TOWER_HALF_SIZE };
+            in_bounds(arena, site.x - half, site.y - half)
+                && in_bounds(arena, site.x + half, site.y + half)
+                && (site.y + half < arena.river_min_y || site.y - half > arena.river_max_y)
+        })
+}
+
+pub fn in_bounds(arena: &ArenaMap, x: i32, y: i32) -> bool {
+    x >= arena.min_x && x <= arena.max_x && y >= arena.min_y && y <= arena.max_y
+}
+
+pub fn on_river(arena: &ArenaMap, y: i32) -> bool {
+    y >= arena.river_min_y && y <= arena.river_max_y
+}
+
+/// Whether `side` may place a troop or building at (`x`, `y`).
+///
+/// Side 0 owns the half below the river (negative y), side 1 the half above it. Once
+/// an enemy princess tower falls, the first `pocket_depth` rows past the river on that
+/// tower's lane open up as well. Standing towers can never be built on.
+pub fn can_deploy(arena: &ArenaMap, towers: &[Tower], side: u8, x: i32, y: i32) -> bool {
+    if !in_bounds(arena, x, y) || on_river(arena, y) {
+        return false;
+    }
+    let on_tower = towers
+        .iter()
+        .any(|t| t.health > 0 && distance(x, y, t.x, t.y) <= tower_half_size(t));
+    if on_tower {
+        return false;
+    }
+
+    let (own_half, rows_past_river) = if side == 0 {
+        (y < arena.river_min_y, y - arena.river_max_y)
+    } else {
+        (y > arena.river_max_y, arena.river_min_y - y)
+    };
+    if own_half {
+        return true;
+    }
+    if rows_past_river > arena.pocket_depth {
+        return false;
+    }
+
+    towers.iter().any(|t| {
+        !t.is_king && t.owner_idx != side && t.health <= 0 && is_right_lane(arena, t.x) == is_right_lane(arena, x)
+    })
+}
+
+/// Whether `side` may place a unit on every one of `positions`. A formation that would
+/// spill off the grid, onto the river or into the enemy half is refused as a whole.
+pub fn can_deploy_all(arena: &ArenaMap, towers: &[Tower], side: u8, positions: &[(i32, i32)]) -> bool {
+    positions.iter().all(|&(x, y)| can_deploy(arena, towers, side, x, y))
+}
+
+/// Lanes split the grid down the middle. On an odd-width grid the centre column belongs
+/// to the right lane.
+fn is_right_lane(arena: &ArenaMap, x: i32) -> bool {
+    2 * x >= arena.min_x + arena.max_x
+}
@@ -0,0 +1,103 @@
+use super::{Layout, TowerTally};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchPhase {
+    Regulation,
+    Overtime,
+    Finished,
+}
+
+/// Match timing, all in unix seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchClock {
+    pub started_at: i64,
+    pub regulation_seconds: u32,
+    pub overtime_seconds: u32,
+}
+
+impl MatchClock {
+    pub fn elapsed(&self, now: i64) -> i64 {
+        (now - self.started_at).max(0)
+    }
+
+    pub fn phase(&self, now: i64) -> MatchPhase {
+        let elapsed = self.elapsed(now);
+        let regulation = self.regulation_seconds as i64;
+        if elapsed < regulation {
+            MatchPhase::Regulation
+        } else if elapsed < regulation + self.overtime_seconds as i64 {
+            MatchPhase::Overtime
+        } else {
+            MatchPhase::Finished
+        }
+    }
+}
+
+/// Whether the match is over at `now`. The final result is then `tally.result()`.
+///
+/// A king tower ends the match at any time. Once regulation runs out, a lead in
+/// princess towers wins; otherwise overtime is sudden death and the first tower
+/// destroyed decides it. When overtime runs out, damage breaks the tie.
+pub fn is_decided(clock: &MatchClock, now: i64, tally: &TowerTally) -> bool {
+    if tally.winner.is_some() {
+        return true;
+    }
+    match clock.phase(now) {
+        MatchPhase::Regulation => false,
+        MatchPhase::Overtime => tally.towers_destroyed[0] != tally.towers_destroyed[1],
+        MatchPhase::Finished => true,
+    }
+}
+
+/// The side that has gone idle: every player on it last acted at least `timeout`
+/// seconds before `now` while someone on the other side has not. None if neither or
+/// both sides are idle.
+pub fn idle_side(layout: &Layout, last_action_at: &[i64], now: i64, timeout: u32) -> Option<u8> {
+    let mut idle = [true; 2];
+    for (i, &at) in last_action_at.iter().enumerate().take(layout.players) {
+        if now - at < timeout as i64 {
+            idle[layout.side_of(i) as usize] = false;
+        }
+    }
+    match idle {
+        [true, false] => Some(0),
+        [false, true] => Some(1),
+        _ => None,
+    }
+}
+
+/// What a surrender does to the match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SurrenderOutcome {
+    /// The clock had already decided the match; its result stands
+    Decided(Option<u8>),
+    /// Everyone on the surrendering side has now left, so the given side wins
+    Forfeit(u8),
+    /// A teammate is still playing and the match goes on
+    Left,
+}
+
+/// Outcome of `player_idx` surrendering at `now`, given who has already left. A result
+/// the clock has already decided stands; otherwise the player's side forfeits once every
+/// player on it has surrendered.
+pub fn surrender_outcome(
+    layout: &Layout,
+    clock: &MatchClock,
+    now: i64,
+    tally: &TowerTally,
+    surrendered: &[bool],
+    player_idx: usize,
+) -> SurrenderOutcome {
+    if is_decided(clock, now, tally) {
+        return SurrenderOutcome::Decided(tally.result());
+    }
+    let side = layout.side_of(player_idx);
+    let side_left = (0..layout.players)
+        .filter(|&i| i != player_idx && layout.side_of(i) == side)
+        .all(|i| surrendered[i]);
+    if side_left {
+        SurrenderOutcome::Forfeit(1 - side)
+    } else {
+        SurrenderOutcome::Left
+    }
+}
@@ -0,0 +1,72 @@
+use crate::constants::*;
+
+use super::{ElixirSchedule, MatchClock};
+
+/// Regeneration multiplier in effect at `now`: normal, double in the final stretch of
+/// regulation, then the overtime rate.
+pub fn elixir_multiplier(schedule: &ElixirSchedule, clock: &MatchClock, now: i64) -> u64 {
+    let (double_from, overtime_from) = phase_boundaries(schedule, clock);
+    if now >= overtime_from {
+        schedule.overtime_multiplier as u64
+    } else if now >= double_from {
+        schedule.double_multiplier as u64
+    } else {
+        1
+    }
+}
+
+fn phase_boundaries(schedule: &ElixirSchedule, clock: &MatchClock) -> (i64, i64) {
+    let overtime_from = clock.started_at + clock.regulation_seconds as i64;
+    let double_from = (overtime_from - schedule.double_elixir_seconds as i64).max(clock.started_at);
+    (double_from, overtime_from)
+}
+
+/// Regenerate every player's elixir for the interval `from..to`, capped at `MAX_ELIXIR`.
+///
+/// The interval is split at the phase boundaries and accrued in elixir-unit
+/// milliseconds. Whatever doesn't add up to a whole unit stays in `carry` for the
+/// next call, so frequent updates earn exactly as much as infrequent ones.
+pub fn regen_elixir(
+    elixir: &mut [u64],
+    carry: &mut u64,
+    schedule: &ElixirSchedule,
+    clock: &MatchClock,
+    from: i64,
+    to: i64,
+) {
+    if to <= from || schedule.ms_per_elixir == 0 {
+        return;
+    }
+    let (double_from, overtime_from) = phase_boundaries(schedule, clock);
+
+    let mut accrued = *carry;
+    let mut t = from;
+    for (end, multiplier) in [
+        (double_from, 1),
+        (overtime_from, schedule.double_multiplier as u64),
+        (i64::MAX, schedule.overtime_multiplier as u64),
+    ] {
+        let seg_end = end.min(to);
+        if seg_end > t {
+            accrued += (seg_end - t) as u64 * 1000 * ELIXIR_UNIT * multiplier;
+            t = seg_end;
+        }
+    }
+
+    let per_unit = schedule.ms_per_elixir as u64;
+    let gained = accrued / per_unit;
+    *carry = accrued % per_unit;
+
+    for e in elixir.iter_mut() {
+        *e = (*e + gained).min(MAX_ELIXIR);
+    }
+}
+
+/// Deduct `cost` from a player's elixir. Returns false and leaves it untouched if short.
+pub fn spend_elixir(elixir: &mut u64, cost: u64) -> bool {
+    if *elixir < cost {
+        return false;
+    }
+    *elixir -= cost;
+    true
+}
@@ -0,0 +1,92 @@
+use crate::constants::*;
+
+/// Queue of deck slot indices for one player: non-empty slots first, padded with
+/// `EMPTY_QUEUE_SLOT`. The first `HAND_SIZE` entries are the playable hand.
+pub type CardQueue = [u8; DECK_SIZE];
+
+/// Unshuffled queue holding every deck slot that has a card in it, given the deck's
+/// card ids (0 for an empty slot).
+pub fn init_queue(card_ids: &[u8; DECK_SIZE]) -> CardQueue {
+    let mut queue = [EMPTY_QUEUE_SLOT; DECK_SIZE];
+    let slots = (0..DECK_SIZE as u8).filter(|&i| card_ids[i as usize] != 0);
+    for (q, slot) in queue.iter_mut().zip(slots) {
+        *q = slot;
+    }
+    queue
+}
+
+fn queue_len(queue: &CardQueue) -> usize {
+    queue.iter().take_while(|&&s| s != EMPTY_QUEUE_SLOT).count()
+}
+
+/// Fisher-Yates shuffle of the filled part of the queue, driven by splitmix64 so any
+/// client holding the seed reproduces the same order.
+pub fn shuffle_queue(queue: &mut CardQueue, seed: u64) {
+    let mut state = seed;
+    let len = queue_len(queue);
+    for i in (1..len).rev() {
+        let j = (next_random(&mut state) % (i as u64 + 1)) as usize;
+        queue.swap(i, j);
+    }
+}
+
+fn next_random(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Hash of `seed_slot` in SlotHashes entries (the sysvar's data past its length prefix:
+/// newest first, each an 8-byte slot and 32-byte hash). Skipped slots have no entry, so
+/// the oldest slot at or after `seed_slot` is taken. None while `seed_slot` is still ahead
+/// of the newest entry.
+pub fn find_slot_hash(entries: &[u8], seed_slot: u64) -> Option<(u64, [u8; 32])> {
+    let mut found = None;
+    for entry in entries.chunks_exact(40) {
+        let slot = u64::from_le_bytes(entry[..8].try_into().ok()?);
+        if slot < seed_slot {
+            break;
+        }
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&entry[8..]);
+        found = Some((slot, hash));
+    }
+    found
+}
+
+/// Battle shuffle seed: the slot hash with `game_id` folded into every 8-byte lane, so
+/// games activated in the same slot still shuffle differently.
+pub fn mix_seed(slot_hash: &[u8; 32], game_id: u64) -> [u8; 32] {
+    let mut seed = *slot_hash;
+    for lane in seed.chunks_mut(8) {
+        for (b, g) in lane.iter_mut().zip(game_id.to_le_bytes()) {
+            *b ^= g;
+        }
+    }
+    seed
+}
+
+/// Per-player shuffle seed taken from the battle's 32-byte seed.
+pub fn player_seed(seed: &[u8; 32], player_idx: usize) -> u64 {
+    let offset = (player_idx % 4) * 8;
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&seed[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+pub fn hand(queue: &CardQueue) -> &[u8] {
+    &queue[..queue_len(queue).min(HAND_SIZE)]
+}
+
+/// Play deck slot `card_idx` from the hand, cycling it to the back of the queue.
+/// Returns false if the slot isn't currently in hand.
+pub fn play_from_hand(queue: &mut CardQueue, card_idx: u8) -> bool {
+    let len = queue_len(queue);
+    let Some(pos) = hand(queue).iter().position(|&s| s == card_idx) else {
+        return false;
+    };
+    queue[pos..len].rotate_left(1);
+    true
+}
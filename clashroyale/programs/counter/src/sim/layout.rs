@@ -0,0 +1,40 @@
+use super::{ArenaMap, Tower};
+
+/// Parameters that differ between 1v1 and 2v2 battles.
+///
+/// Towers are always ordered the same way (indices 0..3 belong to side 0, 3..6 to
+/// side 1, king first) and stand where the arena puts them; only the number of players
+/// and tower HP change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Layout {
+    pub players: usize,
+    pub king_hp: i32,
+    pub princess_hp: i32,
+}
+
+pub const LAYOUT_1V1: Layout = Layout { players: 2, king_hp: 3000, princess_hp: 1500 };
+pub const LAYOUT_2V2: Layout = Layout { players: 4, king_hp: 4000, princess_hp: 2500 };
+
+impl Layout {
+    /// Side (team) a player slot fights for. In 1v1 this is the player index itself.
+    pub fn side_of(&self, player_idx: usize) -> u8 {
+        (player_idx * 2 / self.players) as u8
+    }
+
+    pub fn initial_health(&self, tower: &Tower) -> i32 {
+        if tower.is_king { self.king_hp } else { self.princess_hp }
+    }
+
+    pub fn initial_towers(&self, arena: &ArenaMap) -> [Tower; 6] {
+        std::array::from_fn(|i| {
+            let is_king = i % 3 == 0;
+            Tower {
+                health: if is_king { self.king_hp } else { self.princess_hp },
+                x: arena.towers[i].x,
+                y: arena.towers[i].y,
+                owner_idx: (i / 3) as u8,
+                is_king,
+            }
+        })
+    }
+}
@@ -0,0 +1,13 @@
+use crate::constants::*;
+
+/// Whether a lobby created at `created_at` has waited long enough for anyone, not just
+/// its creator, to cancel it.
+pub fn lobby_expired(created_at: i64, now: i64) -> bool {
+    now >= created_at + LOBBY_TIMEOUT_SECONDS
+}
+
+/// Whether a battle that ended at `ended_at` may be closed even though its rewards were
+/// never claimed.
+pub fn close_grace_over(ended_at: i64, now: i64) -> bool {
+    now >= ended_at + CLOSE_GRACE_SECONDS
+}
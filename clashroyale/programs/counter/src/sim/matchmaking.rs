@@ -0,0 +1,61 @@
+use crate::constants::*;
+
+/// What matchmaking needs to know about a queued player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueuedPlayer {
+    pub mmr: u32,
+    pub enqueued_at: i64,
+}
+
+/// MMR gap a player accepts after waiting `waited` seconds. Starts narrow and widens
+/// until MATCH_WINDOW_MAX.
+pub fn match_window(waited: i64) -> u32 {
+    let grown = MATCH_WINDOW_BASE as i64 + waited.max(0) * MATCH_WINDOW_PER_SECOND as i64;
+    grown.min(MATCH_WINDOW_MAX as i64) as u32
+}
+
+/// Indices of `size` entries to put in one match, or None if nobody fits yet.
+///
+/// Each entry is tried as the anchor in queue order, and the first group that fills
+/// wins. The anchor takes on the players closest to it in MMR, skipping anyone who
+/// doesn't fit with every player already picked. Two players fit when their gap is
+/// within both of their windows.
+pub fn find_match(entries: &[QueuedPlayer], now: i64, size: usize) -> Option<Vec<usize>> {
+    if size == 0 || entries.len() < size {
+        return None;
+    }
+    let window = |e: &QueuedPlayer| match_window(now - e.enqueued_at);
+    let fits = |a: usize, b: usize| {
+        entries[a].mmr.abs_diff(entries[b].mmr) <= window(&entries[a]).min(window(&entries[b]))
+    };
+
+    for a in 0..entries.len() {
+        let mut candidates: Vec<(u32, usize)> = (0..entries.len())
+            .filter(|&j| j != a && fits(a, j))
+            .map(|j| (entries[j].mmr.abs_diff(entries[a].mmr), j))
+            .collect();
+        candidates.sort_unstable();
+
+        let mut group = vec![a];
+        for (_, j) in candidates {
+            if group.len() == size {
+                break;
+            }
+            if group.iter().all(|&g| fits(g, j)) {
+                group.push(j);
+            }
+        }
+        if group.len() == size {
+            return Some(group);
+        }
+    }
+    None
+}
+
+/// Orders four players into two teams of near-equal total MMR: the first two slots are
+/// the strongest and weakest player, the last two the middle pair.
+pub fn balance_teams(mmrs: [u32; 4]) -> [usize; 4] {
+    let mut order = [0, 1, 2, 3];
+    order.sort_by_key(|&i| (mmrs[i], i));
+    [order[0], order[3], order[1], order[2]]
+}
@@ -0,0 +1,37 @@
+//! Deterministic battle simulation.
+//!
+//! Everything in here is integer math over the plain battle data types. It never
+//! touches accounts, sysvars or logging, so the program, off-chain clients and host
+//! tests all step a battle to exactly the same result.
+
+pub mod arena;
+pub mod clock;
+pub mod elixir;
+pub mod hand;
+pub mod layout;
+pub mod lifecycle;
+pub mod matchmaking;
+pub mod progression;
+pub mod rating;
+pub mod spawn;
+pub mod spell;
+pub mod stats;
+pub mod tick;
+pub mod towers;
+pub mod types;
+
+pub use arena::*;
+pub use clock::*;
+pub use elixir::*;
+pub use hand::*;
+pub use layout::*;
+pub use lifecycle::*;
+pub use matchmaking::*;
+pub use progression::*;
+pub use rating::*;
+pub use spawn::*;
+pub use spell::*;
+pub use stats::*;
+pub use tick::*;
+pub use towers::*;
+pub use types::*;
@@ -0,0 +1,103 @@
+use crate::constants::*;
+
+/// One arena on the trophy ladder. The ladder itself is the `ARENAS` table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArenaTier {
+    /// Trophies needed to enter; players never drop back below it
+    pub trophies: u32,
+    /// Cards that can be unlocked once this arena is reached
+    pub unlocks: &'static [u8],
+    /// Battle token reward in this arena, as a percentage of TOKEN_REWARD_AMOUNT
+    pub reward_percent: u64,
+}
+
+/// Tokens paid out for reaching `trophies`: a trophy road milestone or an end-of-season tier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrophyReward {
+    pub trophies: u32,
+    pub tokens: u64,
+}
+
+/// The trophy ladder, lowest arena first. Cards not listed are available from the start.
+pub const ARENAS: [ArenaTier; 10] = [
+    ArenaTier { trophies: 0, unlocks: &[1, 2, 3, 4, 5, 6], reward_percent: 100 },
+    ArenaTier { trophies: 300, unlocks: &[7, 8], reward_percent: 110 },
+    ArenaTier { trophies: 600, unlocks: &[9, 10], reward_percent: 120 },
+    ArenaTier { trophies: 1000, unlocks: &[11, 12], reward_percent: 130 },
+    ArenaTier { trophies: 1300, unlocks: &[13, 14], reward_percent: 140 },
+    ArenaTier { trophies: 1600, unlocks: &[15, 16], reward_percent: 150 },
+    ArenaTier { trophies: 2000, unlocks: &[17, 18], reward_percent: 160 },
+    ArenaTier { trophies: 2300, unlocks: &[19, 20], reward_percent: 170 },
+    ArenaTier { trophies: 2600, unlocks: &[21, 22], reward_percent: 185 },
+    ArenaTier { trophies: 3000, unlocks: &[23, 24], reward_percent: 200 },
+];
+
+/// Trophy road milestones in ascending order. At most 64, one bit each in `road_claimed`.
+pub const TROPHY_ROAD: [TrophyReward; 12] = [
+    TrophyReward { trophies: 100, tokens: 20 },
+    TrophyReward { trophies: 200, tokens: 30 },
+    TrophyReward { trophies: 300, tokens: 50 },
+    TrophyReward { trophies: 450, tokens: 60 },
+    TrophyReward { trophies: 600, tokens: 80 },
+    TrophyReward { trophies: 800, tokens: 100 },
+    TrophyReward { trophies: 1000, tokens: 150 },
+    TrophyReward { trophies: 1300, tokens: 200 },
+    TrophyReward { trophies: 1600, tokens: 250 },
+    TrophyReward { trophies: 2000, tokens: 300 },
+    TrophyReward { trophies: 2600, tokens: 400 },
+    TrophyReward { trophies: 3000, tokens: 500 },
+];
+
+/// End-of-season reward tiers by best trophies that season, ascending. The highest tier
+/// reached pays out.
+pub const SEASON_REWARDS: [TrophyReward; 6] = [
+    TrophyReward { trophies: 300, tokens: 50 },
+    TrophyReward { trophies: 1000, tokens: 150 },
+    TrophyReward { trophies: 1600, tokens: 300 },
+    TrophyReward { trophies: 2300, tokens: 500 },
+    TrophyReward { trophies: 3000, tokens: 800 },
+    TrophyReward { trophies: 4000, tokens: 1200 },
+];
+
+/// Index into `ARENAS` of the highest arena `trophies` has reached.
+pub fn arena_index(trophies: u32) -> usize {
+    ARENAS.iter().rposition(|a| a.trophies <= trophies).unwrap_or(0)
+}
+
+/// Lowest trophy count a player can drop to: the threshold of the highest arena reached.
+pub fn arena_floor(trophies: u32) -> u32 {
+    ARENAS[arena_index(trophies)].trophies
+}
+
+/// Arena a card becomes unlockable in. Cards no arena lists are available from the start.
+pub fn card_arena(card_id: u8) -> usize {
+    ARENAS.iter().position(|a| a.unlocks.contains(&card_id)).unwrap_or(0)
+}
+
+/// Whether a player with `trophies` may unlock `card_id`.
+pub fn can_unlock(trophies: u32, card_id: u8) -> bool {
+    card_arena(card_id) <= arena_index(trophies)
+}
+
+/// Battle token reward for a win in the arena `trophies` puts the player in.
+pub fn battle_reward(trophies: u32) -> u64 {
+    TOKEN_REWARD_AMOUNT * ARENAS[arena_index(trophies)].reward_percent / 100
+}
+
+/// End-of-season tokens for the highest tier `best_trophies` reached, or 0.
+pub fn season_reward(best_trophies: u32) -> u64 {
+    SEASON_REWARDS
+        .iter()
+        .rev()
+        .find(|t| t.trophies <= best_trophies)
+        .map_or(0, |t| t.tokens)
+}
+
+/// Trophies a player starts the next season with: anything above the reset threshold
+/// is halved.
+pub fn soft_reset(trophies: u32) -> u32 {
+    if trophies <= SEASON_RESET_THRESHOLD {
+        return trophies;
+    }
+    SEASON_RESET_THRESHOLD + (trophies - SEASON_RESET_THRESHOLD) / 2
+}
@@ -0,0 +1,108 @@
+use crate::constants::*;
+use super::{arena_floor, battle_reward, Layout};
+
+/// Scores are per-mille: a win is worth 1000, a draw 500.
+pub const SCORE_WIN: i64 = 1000;
+pub const SCORE_DRAW: i64 = 500;
+pub const SCORE_LOSS: i64 = 0;
+
+/// Expected score by rating lead, every 50 points from 0 to 800:
+/// `1000 / (1 + 10^(-lead / 400))`, rounded.
+const EXPECTED_BY_LEAD: [i64; 17] = [
+    500, 571, 640, 703, 760, 808, 849, 882, 909, 930, 947, 960, 969, 977, 983, 987, 990,
+];
+const LEAD_STEP: i64 = 50;
+
+/// How much a rating and trophy count move after one match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RatingChange {
+    pub mmr: i32,
+    pub trophies: i32,
+}
+
+/// Per-mille score a side gets from the result: `winner` is None for a draw.
+pub fn match_score(winner: Option<u8>, side: u8) -> i64 {
+    match winner {
+        None => SCORE_DRAW,
+        Some(w) if w == side => SCORE_WIN,
+        Some(_) => SCORE_LOSS,
+    }
+}
+
+/// Elo expected score of `rating` against `opponent`, per-mille. Interpolated from a
+/// table so every validator and client gets the same integer.
+pub fn expected_score(rating: u32, opponent: u32) -> i64 {
+    let lead = rating as i64 - opponent as i64;
+    let max_lead = LEAD_STEP * (EXPECTED_BY_LEAD.len() as i64 - 1);
+    let abs = lead.abs().min(max_lead);
+
+    let i = (abs / LEAD_STEP) as usize;
+    let frac = abs % LEAD_STEP;
+    let lo = EXPECTED_BY_LEAD[i];
+    let hi = EXPECTED_BY_LEAD[(i + 1).min(EXPECTED_BY_LEAD.len() - 1)];
+    let expected = lo + (hi - lo) * frac / LEAD_STEP;
+
+    if lead >= 0 { expected } else { SCORE_WIN - expected }
+}
+
+/// Elo update for one side. Trophies use a larger factor than MMR so an even win is
+/// worth `TROPHY_K_FACTOR / 2` trophies, more against a stronger opponent.
+pub fn rating_change(rating: u32, opponent: u32, score: i64) -> RatingChange {
+    let surprise = score - expected_score(rating, opponent);
+    RatingChange {
+        mmr: (MMR_K_FACTOR * surprise / SCORE_WIN) as i32,
+        trophies: (TROPHY_K_FACTOR * surprise / SCORE_WIN) as i32,
+    }
+}
+
+/// New `(mmr, trophies)` after `change`. MMR stops at zero, trophies at the arena floor.
+pub fn apply_rating_change(mmr: u32, trophies: u32, change: RatingChange) -> (u32, u32) {
+    let mmr = (mmr as i64 + change.mmr as i64).max(0) as u32;
+    let floor = arena_floor(trophies) as i64;
+    let trophies = (trophies as i64 + change.trophies as i64).max(floor) as u32;
+    (mmr, trophies)
+}
+
+/// Average rating of a team, used as its rating in 2v2.
+pub fn team_rating(ratings: &[u32]) -> u32 {
+    if ratings.is_empty() {
+        return 0;
+    }
+    (ratings.iter().map(|&r| r as u64).sum::<u64>() / ratings.len() as u64) as u32
+}
+
+/// Where one player ends up after a settled match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Settlement {
+    pub mmr: u32,
+    pub trophies: u32,
+    /// Tokens earned: the arena reward for the pre-match trophies on a win, else 0
+    pub reward: u64,
+}
+
+/// Settles every player from their pre-match `(mmr, trophies)`, in player slot order.
+/// Each side is rated by its team average, so in 1v1 a player faces the opponent's own
+/// rating.
+pub fn settle(layout: &Layout, winner: Option<u8>, players: &[(u32, u32)]) -> Vec<Settlement> {
+    let side_rating = |side: u8| {
+        let ratings: Vec<u32> = (0..players.len())
+            .filter(|&i| layout.side_of(i) == side)
+            .map(|i| players[i].0)
+            .collect();
+        team_rating(&ratings)
+    };
+    let ratings = [side_rating(0), side_rating(1)];
+
+    players
+        .iter()
+        .enumerate()
+        .map(|(i, &(mmr, trophies))| {
+            let side = layout.side_of(i);
+            let score = match_score(winner, side);
+            let change = rating_change(ratings[side as usize], ratings[1 - side as usize], score);
+            let (mmr, new_trophies) = apply_rating_change(mmr, trophies, change);
+            let reward = if winner == Some(side) { battle_reward(trophies) } else { 0 };
+            Settlement { mmr, trophies: new_trophies, reward }
+        })
+        .collect()
+}
@@ -0,0 +1,38 @@
+use super::{CardKind, CardStats, Entity, EntityState, ScaledStats};
+
+/// Tiles each unit of a card lands on when deployed at (`x`, `y`).
+///
+/// Formation offsets are written from side 0's point of view (facing +y) and mirrored
+/// for side 1. Positions may fall off the grid; `can_deploy_all` refuses those deploys.
+pub fn unit_positions(stats: &CardStats, side: u8, x: i32, y: i32) -> Vec<(i32, i32)> {
+    let count = (stats.unit_count as usize).clamp(1, stats.formation.len());
+    let facing = if side == 0 { 1 } else { -1 };
+
+    stats.formation[..count]
+        .iter()
+        .map(|o| (x + o.dx as i32, y + o.dy as i32 * facing))
+        .collect()
+}
+
+/// A freshly deployed entity for one unit of a card.
+pub fn spawn_entity(stats: &CardStats, scaled: &ScaledStats, id: u32, side: u8, x: i32, y: i32) -> Entity {
+    Entity {
+        id,
+        owner_idx: side,
+        card_id: stats.card_id,
+        x,
+        y,
+        health: scaled.health,
+        damage: scaled.damage,
+        state: if stats.kind == CardKind::Building { EntityState::Idle } else { EntityState::Moving },
+        target_id: None,
+        speed: stats.speed,
+        range: stats.range,
+        hit_speed: stats.hit_speed,
+        cooldown: 0,
+        targets: stats.targets,
+        flying: stats.flying,
+        kind: stats.kind,
+        lifetime: stats.lifetime,
+    }
+}
@@ -0,0 +1,41 @@
+use crate::constants::*;
+
+use super::{distance, hit_entity, hit_tower, is_alive, tower_half_size, Entity, TickOutcome, Tower};
+
+/// Resolve a spell cast by `side` centred on (`x`, `y`).
+///
+/// Every enemy entity within `radius` takes full `damage`, air or ground. Enemy towers
+/// the area touches take `SPELL_TOWER_DAMAGE_PERCENT` of it, credited like any other
+/// tower damage.
+#[allow(clippy::too_many_arguments)]
+pub fn cast_spell(
+    towers: &mut [Tower],
+    entities: &mut [Entity],
+    side: u8,
+    x: i32,
+    y: i32,
+    radius: i32,
+    damage: i32,
+    towers_destroyed: &mut [u8; 2],
+    damage_dealt: &mut [u64; 2],
+) -> TickOutcome {
+    let mut outcome = TickOutcome::default();
+
+    for e in entities.iter_mut() {
+        if is_alive(e) && e.owner_idx != side && distance(x, y, e.x, e.y) <= radius {
+            hit_entity(e, damage);
+        }
+    }
+
+    let tower_damage = (damage as i64 * SPELL_TOWER_DAMAGE_PERCENT / 100) as i32;
+    for tower in towers.iter_mut() {
+        if tower.owner_idx == side || distance(x, y, tower.x, tower.y) > radius + tower_half_size(tower) {
+            continue;
+        }
+        if let Some(winner) = hit_tower(tower, side, tower_damage, towers_destroyed, damage_dealt) {
+            outcome.winner.get_or_insert(winner);
+        }
+    }
+
+    outcome
+}
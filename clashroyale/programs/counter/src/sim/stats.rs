@@ -0,0 +1,28 @@
+use crate::constants::*;
+
+use super::CardStats;
+
+/// Stats of a card after applying its level, in on-chain units (elixir is x100).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScaledStats {
+    pub cost: u64,
+    pub health: i32,
+    pub damage: i32,
+}
+
+/// Percentage applied to base health and damage: +10% per level above 1.
+pub fn level_multiplier(level: u8) -> u64 {
+    100 + (level.max(1) as u64 - 1) * 10
+}
+
+pub fn scale_stat(base: i32, level: u8) -> i32 {
+    (base as i64 * level_multiplier(level) as i64 / 100) as i32
+}
+
+pub fn scale_stats(base: &CardStats, level: u8) -> ScaledStats {
+    ScaledStats {
+        cost: base.cost as u64 * ELIXIR_UNIT,
+        health: scale_stat(base.health, level),
+        damage: scale_stat(base.damage, level),
+    }
+}
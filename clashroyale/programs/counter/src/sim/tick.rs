@@ -0,0 +1,255 @@
+use crate::constants::*;
+
+use super::{CardKind, Entity, EntityState, TargetType, Tower};
+
+/// Result of advancing the battle by one tick.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TickOutcome {
+    /// Side that destroyed the opposing king tower this tick, if any
+    pub winner: Option<u8>,
+}
+
+/// Target id used by entities attacking the tower at `tower_idx`.
+pub fn tower_target_id(tower_idx: usize) -> u32 {
+    TOWER_TARGET_BASE + tower_idx as u32
+}
+
+/// Tower index encoded in `target_id`, or `None` if it refers to an entity.
+pub fn tower_idx_of(target_id: u32) -> Option<usize> {
+    if target_id >= TOWER_TARGET_BASE {
+        Some((target_id - TOWER_TARGET_BASE) as usize)
+    } else {
+        None
+    }
+}
+
+/// Grid distance between two tiles (diagonal steps count as one).
+pub fn distance(ax: i32, ay: i32, bx: i32, by: i32) -> i32 {
+    (ax - bx).abs().max((ay - by).abs())
+}
+
+pub(super) fn tower_half_size(tower: &Tower) -> i32 {
+    if tower.is_king { KING_TOWER_HALF_SIZE } else { PRINCESS_TOWER_HALF_SIZE }
+}
+
+pub(super) fn is_alive(entity: &Entity) -> bool {
+    entity.state != EntityState::Dead
+}
+
+/// Position of the entity with `id`. Entities are pushed with rising ids and compaction
+/// keeps their order, so the list is always sorted by id.
+fn index_of(entities: &[Entity], id: u32) -> Option<usize> {
+    entities.binary_search_by_key(&id, |e| e.id).ok()
+}
+
+/// Whether an attacker with `targets` may lock on to `target`. Towers are always valid.
+fn can_target(targets: TargetType, target: &Entity) -> bool {
+    match targets {
+        TargetType::Ground => !target.flying,
+        TargetType::Air => true,
+        TargetType::Buildings => target.kind == CardKind::Building,
+    }
+}
+
+/// Buildings lose an even share of their remaining health every tick so they fall
+/// exactly when their lifetime runs out.
+fn decay(entity: &mut Entity) {
+    if !is_alive(entity) || entity.kind != CardKind::Building || entity.lifetime == 0 {
+        return;
+    }
+    let lifetime = entity.lifetime as i32;
+    let loss = (entity.health + lifetime - 1) / lifetime;
+    entity.lifetime -= 1;
+    hit_entity(entity, loss.max(1));
+}
+
+/// Advance every entity and tower by one tick.
+///
+/// Entities act in vector order: buildings decay, then each entity picks a target,
+/// walks towards it (buildings stay put) and hits it once in range and off cooldown.
+/// Towers then fire at the nearest enemy in range. Tower damage is credited to the
+/// attacking side in `damage_dealt`, destroyed princess towers in `towers_destroyed`.
+pub fn step(
+    towers: &mut [Tower],
+    entities: &mut [Entity],
+    towers_destroyed: &mut [u8; 2],
+    damage_dealt: &mut [u64; 2],
+) -> TickOutcome {
+    let mut outcome = TickOutcome::default();
+
+    for i in 0..entities.len() {
+        decay(&mut entities[i]);
+        if !is_alive(&entities[i]) {
+            continue;
+        }
+        if entities[i].cooldown > 0 {
+            entities[i].cooldown -= 1;
+        }
+
+        let Some(target_id) = acquire_target(towers, entities, i) else {
+            entities[i].state = EntityState::Idle;
+            entities[i].target_id = None;
+            continue;
+        };
+        entities[i].target_id = Some(target_id);
+
+        let (tx, ty, reach) = match tower_idx_of(target_id) {
+            Some(t) => (towers[t].x, towers[t].y, tower_half_size(&towers[t])),
+            None => {
+                let Some(target) = index_of(entities, target_id).map(|j| &entities[j]) else {
+                    entities[i].state = EntityState::Idle;
+                    entities[i].target_id = None;
+                    continue;
+                };
+                (target.x, target.y, 0)
+            }
+        };
+        let range = entities[i].range as i32 + reach;
+
+        if distance(entities[i].x, entities[i].y, tx, ty) > range {
+            if entities[i].kind == CardKind::Building {
+                entities[i].state = EntityState::Idle;
+            } else {
+                move_toward(&mut entities[i], tx, ty, range);
+                entities[i].state = EntityState::Moving;
+            }
+            continue;
+        }
+
+        entities[i].state = EntityState::Attacking;
+        if entities[i].cooldown > 0 {
+            continue;
+        }
+        entities[i].cooldown = entities[i].hit_speed;
+
+        let side = entities[i].owner_idx;
+        let damage = entities[i].damage;
+        match tower_idx_of(target_id) {
+            Some(t) => {
+                if let Some(winner) = hit_tower(&mut towers[t], side, damage, towers_destroyed, damage_dealt) {
+                    outcome.winner.get_or_insert(winner);
+                }
+            }
+            None => {
+                if let Some(j) = index_of(entities, target_id) {
+                    hit_entity(&mut entities[j], damage);
+                }
+            }
+        }
+    }
+
+    for tower in towers.iter() {
+        if tower.health <= 0 {
+            continue;
+        }
+        let reach = TOWER_RANGE + tower_half_size(tower);
+        let mut best: Option<(usize, i32)> = None;
+        for (j, e) in entities.iter().enumerate() {
+            if !is_alive(e) || e.owner_idx == tower.owner_idx {
+                continue;
+            }
+            let d = distance(tower.x, tower.y, e.x, e.y);
+            if d <= reach && best.is_none_or(|(_, bd)| d < bd) {
+                best = Some((j, d));
+            }
+        }
+        if let Some((j, _)) = best {
+            let damage = if tower.is_king { KING_TOWER_DAMAGE } else { PRINCESS_TOWER_DAMAGE };
+            hit_entity(&mut entities[j], damage);
+        }
+    }
+
+    outcome
+}
+
+/// Drop dead entities so their slots can be reused. Order of the survivors is kept,
+/// which keeps stepping deterministic.
+pub fn remove_dead(entities: &mut Vec<Entity>) {
+    entities.retain(is_alive);
+}
+
+/// Keep the current target while attacking it, otherwise lock on to the nearest enemy
+/// troop in sight that this entity can hit, falling back to the nearest standing enemy tower.
+fn acquire_target(towers: &[Tower], entities: &[Entity], i: usize) -> Option<u32> {
+    let me = &entities[i];
+
+    if let (EntityState::Attacking, Some(current)) = (&me.state, me.target_id) {
+        let still_valid = match tower_idx_of(current) {
+            Some(t) => towers.get(t).is_some_and(|t| t.health > 0),
+            None => index_of(entities, current).is_some_and(|j| is_alive(&entities[j])),
+        };
+        if still_valid {
+            return Some(current);
+        }
+    }
+
+    let mut best: Option<(u32, i32)> = None;
+    for e in entities.iter() {
+        if !is_alive(e) || e.owner_idx == me.owner_idx || !can_target(me.targets, e) {
+            continue;
+        }
+        let d = distance(me.x, me.y, e.x, e.y);
+        if d <= SIGHT_RANGE && best.is_none_or(|(_, bd)| d < bd) {
+            best = Some((e.id, d));
+        }
+    }
+    if best.is_some() {
+        return best.map(|(id, _)| id);
+    }
+
+    for (t, tower) in towers.iter().enumerate() {
+        if tower.health <= 0 || tower.owner_idx == me.owner_idx {
+            continue;
+        }
+        let d = distance(me.x, me.y, tower.x, tower.y);
+        if best.is_none_or(|(_, bd)| d < bd) {
+            best = Some((tower_target_id(t), d));
+        }
+    }
+    best.map(|(id, _)| id)
+}
+
+fn move_toward(entity: &mut Entity, tx: i32, ty: i32, range: i32) {
+    for _ in 0..entity.speed {
+        if distance(entity.x, entity.y, tx, ty) <= range {
+            break;
+        }
+        entity.x += (tx - entity.x).signum();
+        entity.y += (ty - entity.y).signum();
+    }
+}
+
+pub(super) fn hit_entity(entity: &mut Entity, damage: i32) {
+    entity.health -= damage;
+    if entity.health <= 0 {
+        entity.health = 0;
+        entity.state = EntityState::Dead;
+        entity.target_id = None;
+    }
+}
+
+/// Apply `damage` from `side` to a tower. Returns the winning side if it was a king tower that fell.
+pub(super) fn hit_tower(
+    tower: &mut Tower,
+    side: u8,
+    damage: i32,
+    towers_destroyed: &mut [u8; 2],
+    damage_dealt: &mut [u64; 2],
+) -> Option<u8> {
+    if tower.health <= 0 {
+        return None;
+    }
+    let removed = damage.min(tower.health);
+    tower.health -= removed;
+    damage_dealt[side as usize] += removed as u64;
+
+    if tower.health > 0 {
+        return None;
+    }
+    if tower.is_king {
+        Some(side)
+    } else {
+        towers_destroyed[side as usize] += 1;
+        None
+    }
+}
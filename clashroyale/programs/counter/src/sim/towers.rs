@@ -0,0 +1,54 @@
+use super::{Layout, Tower};
+
+/// Tower bookkeeping derived purely from current tower health.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TowerTally {
+    /// Enemy princess towers each side has destroyed
+    pub towers_destroyed: [u8; 2],
+    /// HP each side has removed from enemy towers
+    pub damage_dealt: [u64; 2],
+    /// Side that destroyed the opposing king tower, if any
+    pub winner: Option<u8>,
+}
+
+/// Recount destroyed towers and damage dealt from the tower array.
+///
+/// Each tower's damage is credited to the side opposing its owner. A destroyed king
+/// tower is an instant win for the other side.
+pub fn tally_towers(towers: &[Tower], layout: &Layout) -> TowerTally {
+    let mut tally = TowerTally::default();
+
+    for tower in towers.iter() {
+        let attacker = 1 - tower.owner_idx as usize;
+        let lost = layout.initial_health(tower) - tower.health.max(0);
+        tally.damage_dealt[attacker] += lost.max(0) as u64;
+
+        if tower.health > 0 {
+            continue;
+        }
+        if tower.is_king {
+            tally.winner.get_or_insert(attacker as u8);
+        } else {
+            tally.towers_destroyed[attacker] += 1;
+        }
+    }
+
+    tally
+}
+
+impl TowerTally {
+    /// Match result from tower state alone: king tower, then princess towers
+    /// destroyed, then damage dealt. `None` is a draw.
+    pub fn result(&self) -> Option<u8> {
+        if self.winner.is_some() {
+            return self.winner;
+        }
+        if self.towers_destroyed[0] != self.towers_destroyed[1] {
+            return Some(if self.towers_destroyed[0] > self.towers_destroyed[1] { 0 } else { 1 });
+        }
+        if self.damage_dealt[0] != self.damage_dealt[1] {
+            return Some(if self.damage_dealt[0] > self.damage_dealt[1] { 0 } else { 1 });
+        }
+        None
+    }
+}
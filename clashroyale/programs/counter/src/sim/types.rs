@@ -0,0 +1,128 @@
+//! Plain copies of the battle data kept in accounts. `state` converts its Anchor types
+//! to and from these at the instruction boundary.
+
+use crate::constants::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tower {
+    pub health: i32,
+    pub x: i32,
+    pub y: i32,
+    pub owner_idx: u8,
+    pub is_king: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entity {
+    pub id: u32,
+    pub owner_idx: u8,
+    pub card_id: u8,
+    pub x: i32,
+    pub y: i32,
+    pub health: i32,
+    pub damage: i32,
+    pub state: EntityState,
+    pub target_id: Option<u32>,
+    /// Tiles moved per tick
+    pub speed: u8,
+    /// Attack range in tiles
+    pub range: u8,
+    /// Ticks between attacks
+    pub hit_speed: u8,
+    /// Ticks until the next attack is ready
+    pub cooldown: u8,
+    pub targets: TargetType,
+    pub flying: bool,
+    pub kind: CardKind,
+    /// Ticks left before a building decays away (0 = no lifetime)
+    pub lifetime: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityState {
+    Idle,
+    Moving,
+    Attacking,
+    Dead,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetType {
+    /// Ground units and buildings
+    Ground,
+    /// Air and ground units and buildings
+    Air,
+    /// Buildings and towers only
+    Buildings,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardKind {
+    Troop,
+    /// Resolves instantly on deploy as area damage; never becomes an entity
+    Spell,
+    /// Stationary entity that decays over its lifetime and draws building-targeting troops
+    Building,
+}
+
+/// The parts of a card's level 1 stats the simulation uses. Timings are in ticks and
+/// distances in tiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardStats {
+    pub card_id: u8,
+    pub cost: u8,
+    pub health: i32,
+    pub damage: i32,
+    pub hit_speed: u8,
+    /// Attack range, or the effect radius for spells
+    pub range: u8,
+    pub speed: u8,
+    pub targets: TargetType,
+    pub flying: bool,
+    pub kind: CardKind,
+    /// Ticks a building stands before decaying away (0 for other kinds)
+    pub lifetime: u16,
+    /// Units spawned per deploy; the first `unit_count` formation slots are used
+    pub unit_count: u8,
+    pub formation: [FormationOffset; MAX_UNITS_PER_CARD],
+}
+
+/// Position of one unit relative to the deploy tile, from side 0's point of view.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FormationOffset {
+    pub dx: i8,
+    pub dy: i8,
+}
+
+/// Deployable grid and tower positions in tile coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArenaMap {
+    pub min_x: i32,
+    pub max_x: i32,
+    pub min_y: i32,
+    pub max_y: i32,
+    /// River rows (inclusive). Nothing can be deployed on them.
+    pub river_min_y: i32,
+    pub river_max_y: i32,
+    /// Rows past the river that open on a lane once the enemy princess tower there falls
+    pub pocket_depth: i32,
+    /// Tower centres in layout order: side 0's king, left and right princess, then side 1's
+    pub towers: [TowerSite; 6],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TowerSite {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Elixir regeneration schedule. Double elixir covers the last `double_elixir_seconds`
+/// of regulation; overtime uses its own multiplier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElixirSchedule {
+    /// Milliseconds to regenerate one elixir at the normal rate
+    pub ms_per_elixir: u32,
+    pub double_elixir_seconds: u32,
+    pub double_multiplier: u8,
+    pub overtime_multiplier: u8,
+}
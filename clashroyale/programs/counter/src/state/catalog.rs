@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::sim;
+
+/// Admin-managed card stats, seeded by [b"catalog"]. Deploys read base stats from here.
+#[account]
+#[derive(InitSpace)]
+pub struct CardCatalog {
+    #[max_len(MAX_CATALOG_CARDS)]
+    pub cards: Vec<CardStats>,
+    pub bump: u8,
+}
+
+impl CardCatalog {
+    pub fn get(&self, card_id: u8) -> Option<&CardStats> {
+        self.cards.iter().find(|c| c.card_id == card_id)
+    }
+}
+
+/// Level 1 stats of a card. Elixir cost is in whole elixir, timings are in ticks and
+/// distances in tiles.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Debug, PartialEq, Eq)]
+pub struct CardStats {
+    pub card_id: u8,
+    pub cost: u8,
+    pub health: i32,
+    pub damage: i32,
+    pub hit_speed: u8,
+    /// Attack range, or the effect radius for spells
+    pub range: u8,
+    pub speed: u8,
+    pub targets: TargetType,
+    /// Flying units can only be hit by `TargetType::Air` attackers and towers
+    pub flying: bool,
+    pub rarity: Rarity,
+    pub kind: CardKind,
+    /// Ticks a building stands before decaying away (0 for other kinds)
+    pub lifetime: u16,
+    /// Units spawned per deploy; the first `unit_count` formation slots are used
+    pub unit_count: u8,
+    pub formation: [FormationOffset; MAX_UNITS_PER_CARD],
+}
+
+/// Position of one unit relative to the deploy tile, from side 0's point of view.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, Default, PartialEq, Eq)]
+pub struct FormationOffset {
+    pub dx: i8,
+    pub dy: i8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, PartialEq, Eq)]
+pub enum TargetType {
+    /// Ground units and buildings
+    Ground,
+    /// Air and ground units and buildings
+    Air,
+    /// Buildings and towers only
+    Buildings,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, PartialEq, Eq)]
+pub enum Rarity {
+    Common,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, PartialEq, Eq)]
+pub enum CardKind {
+    Troop,
+    /// Resolves instantly on deploy as area damage; never becomes an entity
+    Spell,
+    /// Stationary entity that decays over its lifetime and draws building-targeting troops
+    Building,
+}
+
+impl From<&CardStats> for sim::CardStats {
+    fn from(c: &CardStats) -> Self {
+        sim::CardStats {
+            card_id: c.card_id,
+            cost: c.cost,
+            health: c.health,
+            damage: c.damage,
+            hit_speed: c.hit_speed,
+            range: c.range,
+            speed: c.speed,
+            targets: c.targets.into(),
+            flying: c.flying,
+            kind: c.kind.into(),
+            lifetime: c.lifetime,
+            unit_count: c.unit_count,
+            formation: c.formation.map(|o| sim::FormationOffset { dx: o.dx, dy: o.dy }),
+        }
+    }
+}
+
+impl From<TargetType> for sim::TargetType {
+    fn from(t: TargetType) -> Self {
+        match t {
+            TargetType::Ground => sim::TargetType::Ground,
+            TargetType::Air => sim::TargetType::Air,
+            TargetType::Buildings => sim::TargetType::Buildings,
+        }
+    }
+}
+
+impl From<sim::TargetType> for TargetType {
+    fn from(t: sim::TargetType) -> Self {
+        match t {
+            sim::TargetType::Ground => TargetType::Ground,
+            sim::TargetType::Air => TargetType::Air,
+            sim::TargetType::Buildings => TargetType::Buildings,
+        }
+    }
+}
+
+impl From<CardKind> for sim::CardKind {
+    fn from(k: CardKind) -> Self {
+        match k {
+            CardKind::Troop => sim::CardKind::Troop,
+            CardKind::Spell => sim::CardKind::Spell,
+            CardKind::Building => sim::CardKind::Building,
+        }
+    }
+}
+
+impl From<sim::CardKind> for CardKind {
+    fn from(k: sim::CardKind) -> Self {
+        match k {
+            sim::CardKind::Troop => CardKind::Troop,
+            sim::CardKind::Spell => CardKind::Spell,
+            sim::CardKind::Building => CardKind::Building,
+        }
+    }
+}
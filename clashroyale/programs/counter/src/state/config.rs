@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::sim;
+
+/// Global game settings, seeded by [b"config"]. Battles copy what they need at creation
+/// so changing the config never affects a match in progress.
+#[account]
+#[derive(InitSpace)]
+pub struct GameConfig {
+    pub admin: Pubkey,
+    pub regulation_seconds: u32,
+    pub overtime_seconds: u32,
+    /// Seconds a side may go without deploying before the other can claim the win
+    pub afk_timeout_seconds: u32,
+    pub elixir_schedule: ElixirSchedule,
+    pub arena: ArenaMap,
+    pub bump: u8,
+}
+
+/// Elixir regeneration schedule. Double elixir covers the last `double_elixir_seconds`
+/// of regulation; overtime uses its own multiplier.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, PartialEq, Eq)]
+pub struct ElixirSchedule {
+    /// Milliseconds to regenerate one elixir at the normal rate
+    pub ms_per_elixir: u32,
+    pub double_elixir_seconds: u32,
+    pub double_multiplier: u8,
+    pub overtime_multiplier: u8,
+}
+
+/// Deployable grid and tower positions in tile coordinates. Clients render and validate
+/// placement from the same numbers.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, PartialEq, Eq)]
+pub struct ArenaMap {
+    pub min_x: i32,
+    pub max_x: i32,
+    pub min_y: i32,
+    pub max_y: i32,
+    /// River rows (inclusive). Nothing can be deployed on them.
+    pub river_min_y: i32,
+    pub river_max_y: i32,
+    /// Rows past the river that open on a lane once the enemy princess tower there falls
+    pub pocket_depth: i32,
+    /// Tower centres in layout order: side 0's king, left and right princess, then side 1's
+    pub towers: [TowerSite; 6],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, PartialEq, Eq)]
+pub struct TowerSite {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl From<ElixirSchedule> for sim::ElixirSchedule {
+    fn from(s: ElixirSchedule) -> Self {
+        sim::ElixirSchedule {
+            ms_per_elixir: s.ms_per_elixir,
+            double_elixir_seconds: s.double_elixir_seconds,
+            double_multiplier: s.double_multiplier,
+            overtime_multiplier: s.overtime_multiplier,
+        }
+    }
+}
+
+impl From<ArenaMap> for sim::ArenaMap {
+    fn from(a: ArenaMap) -> Self {
+        sim::ArenaMap {
+            min_x: a.min_x,
+            max_x: a.max_x,
+            min_y: a.min_y,
+            max_y: a.max_y,
+            river_min_y: a.river_min_y,
+            river_max_y: a.river_max_y,
+            pocket_depth: a.pocket_depth,
+            towers: a.towers.map(|t| sim::TowerSite { x: t.x, y: t.y }),
+        }
+    }
+}
+
+impl From<sim::ArenaMap> for ArenaMap {
+    fn from(a: sim::ArenaMap) -> Self {
+        ArenaMap {
+            min_x: a.min_x,
+            max_x: a.max_x,
+            min_y: a.min_y,
+            max_y: a.max_y,
+            river_min_y: a.river_min_y,
+            river_max_y: a.river_max_y,
+            pocket_depth: a.pocket_depth,
+            towers: a.towers.map(|t| TowerSite { x: t.x, y: t.y }),
+        }
+    }
+}
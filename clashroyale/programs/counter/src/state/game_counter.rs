@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Hands out battle ids, seeded by [b"game_counter"]. One counter serves both 1v1 and
+/// 2v2 battles, so an id is unique across modes. Creating a game takes `next_game_id`,
+/// so clients read it to derive the new battle's PDA (see `CreateGame`).
+#[account]
+#[derive(InitSpace)]
+pub struct GameCounter {
+    pub next_game_id: u64,
+    pub bump: u8,
+}
+
+impl GameCounter {
+    /// Returns the next id and advances the counter.
+    pub fn take_id(&mut self) -> u64 {
+        let id = self.next_game_id;
+        self.next_game_id += 1;
+        id
+    }
+}
+
+/// Where to find a battle: `team_size` 1 is seeded [b"battle", id], 2 is [b"battle2v2", id].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, PartialEq, Eq)]
+pub struct GameRef {
+    pub game_id: u64,
+    pub team_size: u8,
+}
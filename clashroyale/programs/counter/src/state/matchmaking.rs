@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::sim;
+use super::DeckCard;
+
+/// Players waiting for a ranked match, seeded by [b"match_queue", &[team_size]]: team
+/// size 1 for 1v1, 2 for 2v2. Entries are kept in the order they joined.
+#[account]
+#[derive(InitSpace)]
+pub struct MatchQueue {
+    pub team_size: u8,
+    #[max_len(MAX_QUEUE_ENTRIES)]
+    pub entries: Vec<QueueEntry>,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, PartialEq, Eq)]
+pub struct QueueEntry {
+    pub player: Pubkey,
+    pub mmr: u32,
+    /// Deck and levels as they were when the player queued
+    pub deck: [DeckCard; DECK_SIZE],
+    pub enqueued_at: i64,
+}
+
+impl From<&QueueEntry> for sim::QueuedPlayer {
+    fn from(e: &QueueEntry) -> Self {
+        sim::QueuedPlayer { mmr: e.mmr, enqueued_at: e.enqueued_at }
+    }
+}
+
+impl MatchQueue {
+    /// The queue as `sim::find_match` sees it, in the same order as `entries`.
+    pub fn sim_entries(&self) -> Vec<sim::QueuedPlayer> {
+        self.entries.iter().map(sim::QueuedPlayer::from).collect()
+    }
+}
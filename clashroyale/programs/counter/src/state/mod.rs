@@ -1,8 +1,19 @@
+pub mod catalog;
 pub mod clans;
+pub mod config;
+pub mod game_counter;
+pub mod matchmaking;
+pub mod season;
+pub use catalog::*;
 pub use clans::*;
+pub use config::*;
+pub use game_counter::*;
+pub use matchmaking::*;
+pub use season::*;
 
 use anchor_lang::prelude::*;
 use crate::constants::*;
+use crate::sim::{self, MatchClock};
 
 #[account]
 #[derive(InitSpace)]
@@ -15,8 +26,112 @@ pub struct PlayerProfile {
     #[max_len(20)]
     pub username: String,
     pub trophies: u32,
+    /// Bit `i` is set once TROPHY_ROAD[i] has been claimed
+    pub road_claimed: u64,
+    /// Season the fields below are tracking
+    pub season: u32,
+    pub season_best_trophies: u32,
+    /// Season with the highest best trophies so far, and that count
+    pub best_season: u32,
+    pub best_season_trophies: u32,
+    /// End-of-season tokens earned but not yet claimed
+    pub pending_season_reward: u64,
+    /// Lobbies this player created, newest last
+    #[max_len(MAX_CREATED_GAMES)]
+    pub created_games: Vec<GameRef>,
+    /// Battle this player was last seated in. Checked, and cleared once that battle is
+    /// over, when they next join a match queue.
+    pub active_game: Option<GameRef>,
 }
 
+/// Player profile as first deployed, before the trophy road, season and lobby fields.
+/// Only `migrate_profile` reads it, to move old accounts onto the current layout.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyPlayerProfile {
+    pub authority: Pubkey,
+    pub mmr: u32,
+    pub deck: [u8; 8],
+    #[max_len(MAX_INVENTORY)]
+    pub inventory: Vec<CardProgress>,
+    #[max_len(20)]
+    pub username: String,
+    pub trophies: u32,
+}
+
+impl From<LegacyPlayerProfile> for PlayerProfile {
+    fn from(p: LegacyPlayerProfile) -> Self {
+        Self {
+            authority: p.authority,
+            mmr: p.mmr,
+            deck: p.deck,
+            inventory: p.inventory,
+            username: p.username,
+            trophies: p.trophies,
+            road_claimed: 0,
+            season: 0,
+            season_best_trophies: 0,
+            best_season: 0,
+            best_season_trophies: 0,
+            pending_season_reward: 0,
+            created_games: Vec::new(),
+            active_game: None,
+        }
+    }
+}
+
+impl PlayerProfile {
+    /// The current deck with each card's level, as copied into a battle at join time.
+    /// Slots holding a card that is no longer in the inventory are left empty.
+    pub fn deck_snapshot(&self, rule: LevelRule) -> [DeckCard; DECK_SIZE] {
+        let mut snapshot = [DeckCard::default(); DECK_SIZE];
+        for (slot, &card_id) in snapshot.iter_mut().zip(self.deck.iter()) {
+            if let Some(card) = self.inventory.iter().find(|c| c.card_id == card_id && card_id != 0) {
+                *slot = DeckCard { card_id, level: rule.apply(card.level) };
+            }
+        }
+        snapshot
+    }
+
+    /// Brings the profile into `season`: banks the reward for the season last played,
+    /// updates the best-season record and soft-resets trophies once for every season
+    /// that ended since, played or not. A profile that has never been in a season
+    /// (season 0) just joins with its trophies as they are. No-op if already there.
+    pub fn roll_season(&mut self, season: u32) {
+        if self.season >= season {
+            return;
+        }
+        if self.season > 0 {
+            self.pending_season_reward += sim::season_reward(self.season_best_trophies);
+            if self.season_best_trophies > self.best_season_trophies {
+                self.best_season = self.season;
+                self.best_season_trophies = self.season_best_trophies;
+            }
+            for _ in self.season..season {
+                let reset = sim::soft_reset(self.trophies);
+                if reset == self.trophies {
+                    break;
+                }
+                self.trophies = reset;
+            }
+        }
+        self.season = season;
+        self.season_best_trophies = self.trophies;
+    }
+
+    /// Sets trophies and raises the season best to match.
+    pub fn set_trophies(&mut self, trophies: u32) {
+        self.trophies = trophies;
+        self.season_best_trophies = self.season_best_trophies.max(trophies);
+    }
+
+    /// Remembers a lobby this player created, forgetting the oldest once full.
+    pub fn record_created_game(&mut self, game: GameRef) {
+        if self.created_games.len() >= MAX_CREATED_GAMES {
+            self.created_games.remove(0);
+        }
+        self.created_games.push(game);
+    }
+}
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Debug)]
 pub struct CardProgress {
@@ -26,6 +141,42 @@ pub struct CardProgress {
     pub amount: u32,
 }
 
+/// A deck slot as fielded in a battle. `card_id` 0 is an empty slot.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, Default, PartialEq, Eq)]
+pub struct DeckCard {
+    pub card_id: u8,
+    pub level: u8,
+}
+
+/// How card levels are adjusted when decks are snapshotted into a battle.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, Default, PartialEq, Eq)]
+pub enum LevelRule {
+    /// Levels are used as owned
+    #[default]
+    Standard,
+    /// Levels above the cap are lowered to it
+    Cap(u8),
+    /// Every card plays at this level
+    Fixed(u8),
+}
+
+impl LevelRule {
+    pub fn is_valid(self) -> bool {
+        match self {
+            LevelRule::Standard => true,
+            LevelRule::Cap(level) | LevelRule::Fixed(level) => (1..=MAX_CARD_LEVEL).contains(&level),
+        }
+    }
+
+    pub fn apply(self, level: u8) -> u8 {
+        match self {
+            LevelRule::Standard => level,
+            LevelRule::Cap(cap) => level.min(cap),
+            LevelRule::Fixed(fixed) => fixed,
+        }
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct CardMintState {
@@ -40,19 +191,58 @@ pub struct CardMintState {
 pub struct BattleState {
     pub game_id: u64,
     pub players: [Pubkey; 2],
+    /// Account that paid the battle's rent and gets it back when it is closed
+    pub payer: Pubkey,
     pub status: GameStatus,
     pub tick_count: u64,
     pub elixir: [u64; 2],
     pub towers: [Tower; 6],
     #[max_len(MAX_ENTITIES)]
     pub entities: Vec<Entity>,
+    /// Id handed to the next spawned entity; never reused
+    pub next_entity_id: u32,
     pub winner: Option<u8>,
-    pub trophies_minted: bool,
+    /// Whether ratings and rewards have been applied to every participant
+    pub settled: bool,
     /// How many of the enemy's towers each player has destroyed (princess only)
     pub towers_destroyed: [u8; 2],
     /// Total HP damage dealt to enemy towers by each player
     pub damage_dealt: [u64; 2],
     pub last_update_time: i64,
+    /// Unix timestamp the simulation was last advanced to
+    pub last_tick_time: i64,
+    /// Unix timestamp the lobby was created
+    pub created_at: i64,
+    /// Unix timestamp the game became Active
+    pub started_at: i64,
+    /// Unix timestamp the game became Completed
+    pub ended_at: i64,
+    pub end_reason: EndReason,
+    /// Unix timestamp of each player's last deploy, or of activation if none yet
+    pub last_action_at: [i64; 2],
+    pub regulation_seconds: u32,
+    pub overtime_seconds: u32,
+    pub afk_timeout_seconds: u32,
+    pub elixir_schedule: ElixirSchedule,
+    /// Elixir-unit milliseconds accrued towards the next whole unit
+    pub elixir_carry: u64,
+    pub arena: ArenaMap,
+    pub level_rule: LevelRule,
+    /// Players allowed to join; empty means anyone may
+    #[max_len(1)]
+    pub invited: Vec<Pubkey>,
+    /// Friendly battles are never settled: trophies, MMR and rewards stay as they were
+    pub friendly: bool,
+    /// Each player's deck and card levels, fixed when they joined
+    pub decks: [[DeckCard; DECK_SIZE]; 2],
+    /// Per-player queues of deck slots; the first HAND_SIZE are the hand
+    pub card_queues: [[u8; DECK_SIZE]; 2],
+    /// Seed the queues were shuffled with: hash of `seed_slot` mixed with game_id
+    pub shuffle_seed: [u8; 32],
+    /// Slot whose hash seeds the shuffle: SEED_SLOT_DELAY after activation until the
+    /// hands are dealt, then the slot the hash actually came from
+    pub seed_slot: u64,
+    pub hands_dealt: bool,
 }
 
 #[account]
@@ -60,19 +250,138 @@ pub struct BattleState {
 pub struct BattleState2v2 {
     pub game_id: u64,
     pub players: [Pubkey; 4],
+    /// Account that paid the battle's rent and gets it back when it is closed
+    pub payer: Pubkey,
     pub status: GameStatus,
     pub tick_count: u64,
     pub elixir: [u64; 4],
     pub towers: [Tower; 6],
     #[max_len(MAX_ENTITIES)]
     pub entities: Vec<Entity>,
+    /// Id handed to the next spawned entity; never reused
+    pub next_entity_id: u32,
     pub winner: Option<u8>, // 0 for Team A (p0, p1), 1 for Team B (p2, p3)
-    pub trophies_minted: [bool; 4],
+    /// Players who have surrendered and left; a team forfeits once both have
+    pub surrendered: [bool; 4],
+    /// Whether ratings and rewards have been applied to every participant
+    pub settled: bool,
     /// How many of the enemy's towers each team has destroyed (princess only)
     pub towers_destroyed: [u8; 2],
     /// Total HP damage dealt to enemy towers by each team
     pub damage_dealt: [u64; 2],
     pub last_update_time: i64,
+    /// Unix timestamp the simulation was last advanced to
+    pub last_tick_time: i64,
+    /// Unix timestamp the lobby was created
+    pub created_at: i64,
+    /// Unix timestamp the game became Active
+    pub started_at: i64,
+    /// Unix timestamp the game became Completed
+    pub ended_at: i64,
+    pub end_reason: EndReason,
+    /// Unix timestamp of each player's last deploy, or of activation if none yet
+    pub last_action_at: [i64; 4],
+    pub regulation_seconds: u32,
+    pub overtime_seconds: u32,
+    pub afk_timeout_seconds: u32,
+    pub elixir_schedule: ElixirSchedule,
+    /// Elixir-unit milliseconds accrued towards the next whole unit
+    pub elixir_carry: u64,
+    pub arena: ArenaMap,
+    pub level_rule: LevelRule,
+    /// Players allowed to join; empty means anyone may
+    #[max_len(3)]
+    pub invited: Vec<Pubkey>,
+    /// Friendly battles are never settled: trophies, MMR and rewards stay as they were
+    pub friendly: bool,
+    /// Each player's deck and card levels, fixed when they joined
+    pub decks: [[DeckCard; DECK_SIZE]; 4],
+    /// Per-player queues of deck slots; the first HAND_SIZE are the hand
+    pub card_queues: [[u8; DECK_SIZE]; 4],
+    /// Seed the queues were shuffled with: hash of `seed_slot` mixed with game_id
+    pub shuffle_seed: [u8; 32],
+    /// Slot whose hash seeds the shuffle: SEED_SLOT_DELAY after activation until the
+    /// hands are dealt, then the slot the hash actually came from
+    pub seed_slot: u64,
+    pub hands_dealt: bool,
+}
+
+impl BattleState {
+    pub fn clock(&self) -> MatchClock {
+        MatchClock {
+            started_at: self.started_at,
+            regulation_seconds: self.regulation_seconds,
+            overtime_seconds: self.overtime_seconds,
+        }
+    }
+
+    /// Towers as the simulation sees them.
+    pub fn sim_towers(&self) -> [sim::Tower; 6] {
+        self.towers.each_ref().map(sim::Tower::from)
+    }
+
+    /// Entities as the simulation sees them.
+    pub fn sim_entities(&self) -> Vec<sim::Entity> {
+        self.entities.iter().map(sim::Entity::from).collect()
+    }
+
+    /// Writes towers and entities back after the simulation has moved them on.
+    pub fn store_sim(&mut self, towers: [sim::Tower; 6], entities: Vec<sim::Entity>) {
+        self.towers = towers.map(Tower::from);
+        self.entities = entities.into_iter().map(Entity::from).collect();
+    }
+
+    pub fn tally(&self) -> sim::TowerTally {
+        sim::tally_towers(&self.sim_towers(), &sim::LAYOUT_1V1)
+    }
+}
+
+impl BattleState2v2 {
+    pub fn clock(&self) -> MatchClock {
+        MatchClock {
+            started_at: self.started_at,
+            regulation_seconds: self.regulation_seconds,
+            overtime_seconds: self.overtime_seconds,
+        }
+    }
+
+    /// Towers as the simulation sees them.
+    pub fn sim_towers(&self) -> [sim::Tower; 6] {
+        self.towers.each_ref().map(sim::Tower::from)
+    }
+
+    /// Entities as the simulation sees them.
+    pub fn sim_entities(&self) -> Vec<sim::Entity> {
+        self.entities.iter().map(sim::Entity::from).collect()
+    }
+
+    /// Writes towers and entities back after the simulation has moved them on.
+    pub fn store_sim(&mut self, towers: [sim::Tower; 6], entities: Vec<sim::Entity>) {
+        self.towers = towers.map(Tower::from);
+        self.entities = entities.into_iter().map(Entity::from).collect();
+    }
+
+    pub fn tally(&self) -> sim::TowerTally {
+        sim::tally_towers(&self.sim_towers(), &sim::LAYOUT_2V2)
+    }
+}
+
+/// Whether `invited` may be stored on a lobby with `seats` seats: empty for an open
+/// lobby, otherwise exactly one distinct player per open seat, never the creator or the
+/// default key.
+pub fn invites_valid(invited: &[Pubkey], creator: Pubkey, seats: usize) -> bool {
+    if invited.is_empty() {
+        return true;
+    }
+    invited.len() == seats - 1
+        && invited.iter().enumerate().all(|(i, p)| {
+            *p != creator && *p != Pubkey::default() && !invited[..i].contains(p)
+        })
+}
+
+/// Whether `player` may take a seat in a lobby with this invite list.
+pub fn admits(invited: &[Pubkey], player: Pubkey) -> bool {
+    invited.is_empty() || invited.contains(&player)
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Eq)]
@@ -82,6 +391,18 @@ pub enum GameStatus {
     Completed,
 }
 
+/// How a completed battle was decided.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, Default, PartialEq, Eq)]
+pub enum EndReason {
+    /// Towers or the clock decided it
+    #[default]
+    Played,
+    /// The losing side surrendered
+    Forfeit,
+    /// The losing side stopped acting and the other claimed the win
+    Timeout,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Debug)]
 pub struct Tower {
     pub health: i32,
@@ -102,27 +423,107 @@ pub struct Entity {
     pub damage: i32,
     pub state: EntityState,
     pub target_id: Option<u32>,
+    /// Tiles moved per tick
+    pub speed: u8,
+    /// Attack range in tiles
+    pub range: u8,
+    /// Ticks between attacks
+    pub hit_speed: u8,
+    /// Ticks until the next attack is ready
+    pub cooldown: u8,
+    pub targets: TargetType,
+    pub flying: bool,
+    pub kind: CardKind,
+    /// Ticks left before a building decays away (0 = no lifetime)
+    pub lifetime: u16,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, PartialEq, Eq)]
 pub enum EntityState {
     Idle,
     Moving,
     Attacking,
-    Dead
+    Dead,
 }
 
-pub struct CardBaseStats {
-    pub cost: u8,
-    pub health: i32,
-    pub damage: i32
+impl From<&Tower> for sim::Tower {
+    fn from(t: &Tower) -> Self {
+        sim::Tower { health: t.health, x: t.x, y: t.y, owner_idx: t.owner_idx, is_king: t.is_king }
+    }
+}
+
+impl From<sim::Tower> for Tower {
+    fn from(t: sim::Tower) -> Self {
+        Tower { health: t.health, x: t.x, y: t.y, owner_idx: t.owner_idx, is_king: t.is_king }
+    }
+}
+
+impl From<&Entity> for sim::Entity {
+    fn from(e: &Entity) -> Self {
+        sim::Entity {
+            id: e.id,
+            owner_idx: e.owner_idx,
+            card_id: e.card_id,
+            x: e.x,
+            y: e.y,
+            health: e.health,
+            damage: e.damage,
+            state: e.state.into(),
+            target_id: e.target_id,
+            speed: e.speed,
+            range: e.range,
+            hit_speed: e.hit_speed,
+            cooldown: e.cooldown,
+            targets: e.targets.into(),
+            flying: e.flying,
+            kind: e.kind.into(),
+            lifetime: e.lifetime,
+        }
+    }
+}
+
+impl From<sim::Entity> for Entity {
+    fn from(e: sim::Entity) -> Self {
+        Entity {
+            id: e.id,
+            owner_idx: e.owner_idx,
+            card_id: e.card_id,
+            x: e.x,
+            y: e.y,
+            health: e.health,
+            damage: e.damage,
+            state: e.state.into(),
+            target_id: e.target_id,
+            speed: e.speed,
+            range: e.range,
+            hit_speed: e.hit_speed,
+            cooldown: e.cooldown,
+            targets: e.targets.into(),
+            flying: e.flying,
+            kind: e.kind.into(),
+            lifetime: e.lifetime,
+        }
+    }
+}
+
+impl From<EntityState> for sim::EntityState {
+    fn from(s: EntityState) -> Self {
+        match s {
+            EntityState::Idle => sim::EntityState::Idle,
+            EntityState::Moving => sim::EntityState::Moving,
+            EntityState::Attacking => sim::EntityState::Attacking,
+            EntityState::Dead => sim::EntityState::Dead,
+        }
+    }
 }
 
-pub fn get_card_stats(id: u8) -> Option<CardBaseStats> {
-    match id {
-        1 => Some(CardBaseStats { cost: 3, health: 125, damage: 33 }), // Archer 
-        2 => Some(CardBaseStats { cost: 5, health: 2000, damage: 126 }), // Giant
-        3 => Some(CardBaseStats { cost: 4, health: 600, damage: 325 }), // MiniPEKKA
-        _ => Some(CardBaseStats { cost: 3, health: 100, damage: 100 })
+impl From<sim::EntityState> for EntityState {
+    fn from(s: sim::EntityState) -> Self {
+        match s {
+            sim::EntityState::Idle => EntityState::Idle,
+            sim::EntityState::Moving => EntityState::Moving,
+            sim::EntityState::Attacking => EntityState::Attacking,
+            sim::EntityState::Dead => EntityState::Dead,
+        }
     }
 }
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+/// The current ladder season, seeded by [b"season"]. Rolled over permissionlessly once
+/// `ends_at` passes; player profiles catch up lazily.
+#[account]
+#[derive(InitSpace)]
+pub struct Season {
+    /// Starts at 1; profiles at 0 have not played a season yet
+    pub number: u32,
+    pub started_at: i64,
+    pub ends_at: i64,
+    pub bump: u8,
+}
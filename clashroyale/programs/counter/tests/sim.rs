@@ -0,0 +1,917 @@
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize, Pubkey};
+use anchor_lang::Space;
+use counter::constants::*;
+use counter::sim::{self, ArenaMap, CardKind, CardStats, ElixirSchedule, Entity, EntityState, FormationOffset, QueuedPlayer, TargetType, Tower, TowerSite, LAYOUT_1V1, LAYOUT_2V2};
+use counter::state::{self, CardProgress, DeckCard, GameCounter, GameRef, LegacyPlayerProfile, LevelRule, PlayerProfile};
+
+/// A roomier grid than the default so troop paths in these tests stay short and clear.
+const ARENA: ArenaMap = ArenaMap {
+    min_x: -14,
+    max_x: 14,
+    min_y: -22,
+    max_y: 22,
+    river_min_y: -1,
+    river_max_y: 1,
+    pocket_depth: 4,
+    towers: [
+        TowerSite { x: 0, y: -20 },
+        TowerSite { x: -10, y: -15 },
+        TowerSite { x: 10, y: -15 },
+        TowerSite { x: 0, y: 20 },
+        TowerSite { x: -10, y: 15 },
+        TowerSite { x: 10, y: 15 },
+    ],
+};
+
+fn towers() -> [Tower; 6] {
+    LAYOUT_1V1.initial_towers(&ARENA)
+}
+
+fn troop(id: u32, owner_idx: u8, x: i32, y: i32, health: i32, damage: i32) -> Entity {
+    Entity {
+        id,
+        owner_idx,
+        card_id: 3,
+        x,
+        y,
+        health,
+        damage,
+        state: EntityState::Moving,
+        target_id: None,
+        speed: 2,
+        range: 1,
+        hit_speed: 1,
+        cooldown: 0,
+        targets: TargetType::Ground,
+        flying: false,
+        kind: CardKind::Troop,
+        lifetime: 0,
+    }
+}
+
+fn run(towers: &mut [Tower; 6], entities: &mut [Entity], ticks: u32) -> (Option<u8>, [u8; 2], [u64; 2]) {
+    let mut destroyed = [0u8; 2];
+    let mut dealt = [0u64; 2];
+    for _ in 0..ticks {
+        let outcome = sim::step(towers, entities, &mut destroyed, &mut dealt);
+        if outcome.winner.is_some() {
+            return (outcome.winner, destroyed, dealt);
+        }
+    }
+    (None, destroyed, dealt)
+}
+
+#[test]
+fn troop_walks_to_nearest_tower_and_damages_it() {
+    let mut towers = towers();
+    let mut entities = vec![troop(1, 0, 10, 0, 10_000, 100)];
+
+    let (winner, destroyed, dealt) = run(&mut towers, &mut entities, 10);
+
+    assert_eq!(winner, None);
+    assert_eq!(entities[0].state, EntityState::Attacking);
+    assert_eq!(entities[0].target_id, Some(sim::tower_target_id(5)));
+    assert!(towers[5].health < 1500);
+    assert_eq!(dealt[0], (1500 - towers[5].health) as u64);
+    assert_eq!(destroyed, [0, 0]);
+}
+
+#[test]
+fn tower_shoots_down_weak_troop() {
+    let mut towers = towers();
+    let mut entities = vec![troop(1, 1, 10, -10, 60, 10)];
+
+    run(&mut towers, &mut entities, 5);
+
+    assert_eq!(entities[0].state, EntityState::Dead);
+    assert_eq!(entities[0].health, 0);
+}
+
+#[test]
+fn destroying_king_tower_declares_winner() {
+    let mut towers = towers();
+    towers[3].health = 50;
+    towers[4].health = 0;
+    towers[5].health = 0;
+    let mut entities = vec![troop(1, 0, 0, 18, 10_000, 100)];
+
+    let (winner, _, dealt) = run(&mut towers, &mut entities, 3);
+
+    assert_eq!(winner, Some(0));
+    assert_eq!(towers[3].health, 0);
+    assert_eq!(dealt[0], 50);
+}
+
+#[test]
+fn troops_fight_each_other_before_towers() {
+    let mut towers = towers();
+    let mut entities = vec![
+        troop(1, 0, 0, -2, 1000, 300),
+        troop(2, 1, 0, 2, 400, 50),
+    ];
+
+    run(&mut towers, &mut entities, 4);
+
+    assert_eq!(entities[1].state, EntityState::Dead);
+    assert!(entities[0].health < 1000);
+}
+
+#[test]
+fn targets_are_found_by_id_after_compaction() {
+    let mut towers = towers();
+    let mut entities = vec![troop(1, 0, 5, -2, 100, 10), troop(4, 0, 0, -2, 1000, 100), troop(9, 1, 0, 2, 1000, 10)];
+    entities[0].state = EntityState::Dead;
+    sim::remove_dead(&mut entities);
+
+    run(&mut towers, &mut entities, 3);
+
+    assert_eq!(entities[0].target_id, Some(9));
+    assert_eq!(entities[1].target_id, Some(4));
+    assert!(entities[1].health < 1000);
+}
+
+#[test]
+fn stepping_is_deterministic() {
+    let spawn = || {
+        vec![
+            troop(1, 0, -8, -5, 2000, 120),
+            troop(2, 0, 8, -3, 600, 325),
+            troop(3, 1, -6, 4, 1500, 90),
+            troop(4, 1, 9, 6, 800, 200),
+        ]
+    };
+    let (mut ta, mut tb) = (towers(), towers());
+    let (mut ea, mut eb) = (spawn(), spawn());
+
+    let a = run(&mut ta, &mut ea, 40);
+    let b = run(&mut tb, &mut eb, 40);
+
+    assert_eq!(a, b);
+    for (x, y) in ea.iter().zip(eb.iter()) {
+        assert_eq!((x.x, x.y, x.health, &x.state, x.target_id), (y.x, y.y, y.health, &y.state, y.target_id));
+    }
+    for (x, y) in ta.iter().zip(tb.iter()) {
+        assert_eq!(x.health, y.health);
+    }
+}
+
+const SCHEDULE: ElixirSchedule = ElixirSchedule {
+    ms_per_elixir: 2800,
+    double_elixir_seconds: 60,
+    double_multiplier: 2,
+    overtime_multiplier: 3,
+};
+const CLOCK: sim::MatchClock = sim::MatchClock { started_at: 0, regulation_seconds: 180, overtime_seconds: 120 };
+
+#[test]
+fn elixir_regenerates_and_caps() {
+    let mut elixir = [STARTING_ELIXIR, 950, 0, MAX_ELIXIR];
+    let mut carry = 0;
+
+    sim::regen_elixir(&mut elixir, &mut carry, &SCHEDULE, &CLOCK, 0, 14);
+
+    assert_eq!(elixir, [1000, MAX_ELIXIR, 500, MAX_ELIXIR]);
+    assert_eq!(carry, 0);
+}
+
+#[test]
+fn elixir_remainder_carries_between_updates() {
+    let mut once = [0u64; 2];
+    let mut carry_once = 0;
+    sim::regen_elixir(&mut once, &mut carry_once, &SCHEDULE, &CLOCK, 0, 7);
+
+    let mut stepped = [0u64; 2];
+    let mut carry_stepped = 0;
+    for t in 0..7 {
+        sim::regen_elixir(&mut stepped, &mut carry_stepped, &SCHEDULE, &CLOCK, t, t + 1);
+    }
+
+    assert_eq!(once, [250, 250]);
+    assert_eq!(stepped, once);
+    assert_eq!(carry_stepped, carry_once);
+}
+
+#[test]
+fn elixir_rate_follows_match_phase() {
+    assert_eq!(sim::elixir_multiplier(&SCHEDULE, &CLOCK, 119), 1);
+    assert_eq!(sim::elixir_multiplier(&SCHEDULE, &CLOCK, 120), 2);
+    assert_eq!(sim::elixir_multiplier(&SCHEDULE, &CLOCK, 180), 3);
+
+    // 14s spanning normal (7s) and double (7s): 2.5 + 5 elixir
+    let mut elixir = [0u64];
+    let mut carry = 0;
+    sim::regen_elixir(&mut elixir, &mut carry, &SCHEDULE, &CLOCK, 113, 127);
+    assert_eq!(elixir, [750]);
+
+    // 28s of overtime at triple rate
+    let mut elixir = [0u64];
+    sim::regen_elixir(&mut elixir, &mut carry, &SCHEDULE, &CLOCK, 200, 228);
+    assert_eq!(elixir, [MAX_ELIXIR]);
+}
+
+#[test]
+fn spending_more_elixir_than_available_is_rejected() {
+    let mut elixir = 300;
+
+    assert!(!sim::spend_elixir(&mut elixir, 400));
+    assert_eq!(elixir, 300);
+    assert!(sim::spend_elixir(&mut elixir, 300));
+    assert_eq!(elixir, 0);
+}
+
+#[test]
+fn stats_scale_ten_percent_per_level() {
+    let giant = CardStats {
+        card_id: 2,
+        cost: 5,
+        health: 2000,
+        damage: 126,
+        hit_speed: 2,
+        range: 1,
+        speed: 1,
+        targets: TargetType::Buildings,
+        flying: false,
+        kind: CardKind::Troop,
+        lifetime: 0,
+        unit_count: 1,
+        formation: [FormationOffset::default(); MAX_UNITS_PER_CARD],
+    };
+
+    let l1 = sim::scale_stats(&giant, 1);
+    let l11 = sim::scale_stats(&giant, 11);
+
+    assert_eq!((l1.cost, l1.health, l1.damage), (500, 2000, 126));
+    assert_eq!((l11.health, l11.damage), (4000, 252));
+}
+
+#[test]
+fn layouts_map_players_to_sides() {
+    assert_eq!((0..2).map(|p| LAYOUT_1V1.side_of(p)).collect::<Vec<_>>(), [0, 1]);
+    assert_eq!((0..4).map(|p| LAYOUT_2V2.side_of(p)).collect::<Vec<_>>(), [0, 0, 1, 1]);
+    assert_eq!(LAYOUT_2V2.initial_towers(&ARENA)[0].health, 4000);
+}
+
+#[test]
+fn tally_credits_damage_and_princess_towers_to_attacker() {
+    let mut towers = LAYOUT_2V2.initial_towers(&ARENA);
+    towers[1].health = 0;
+    towers[4].health = 2000;
+    towers[0].health = 3900;
+
+    let tally = sim::tally_towers(&towers, &LAYOUT_2V2);
+
+    assert_eq!(tally.towers_destroyed, [0, 1]);
+    assert_eq!(tally.damage_dealt, [500, 2600]);
+    assert_eq!(tally.winner, None);
+}
+
+#[test]
+fn tally_declares_winner_on_king_tower() {
+    let mut towers = towers();
+    towers[3].health = -20;
+
+    let tally = sim::tally_towers(&towers, &LAYOUT_1V1);
+
+    assert_eq!(tally.winner, Some(0));
+    assert_eq!(tally.damage_dealt[0], 3000);
+}
+
+#[test]
+fn tick_accounting_matches_tally() {
+    let mut towers = towers();
+    let mut entities = vec![troop(1, 0, 10, 0, 10_000, 100), troop(2, 1, -10, 0, 10_000, 70)];
+
+    let (_, destroyed, dealt) = run(&mut towers, &mut entities, 30);
+    let tally = sim::tally_towers(&towers, &LAYOUT_1V1);
+
+    assert_eq!(tally.towers_destroyed, destroyed);
+    assert_eq!(tally.damage_dealt, dealt);
+}
+
+#[test]
+fn result_prefers_towers_then_damage() {
+    let mut towers = towers();
+    assert_eq!(sim::tally_towers(&towers, &LAYOUT_1V1).result(), None);
+
+    towers[0].health = 2900;
+    towers[3].health = 2950;
+    assert_eq!(sim::tally_towers(&towers, &LAYOUT_1V1).result(), Some(1));
+
+    towers[4].health = 0;
+    assert_eq!(sim::tally_towers(&towers, &LAYOUT_1V1).result(), Some(0));
+
+    towers[1].health = 0;
+    towers[2].health = 0;
+    assert_eq!(sim::tally_towers(&towers, &LAYOUT_1V1).result(), Some(1));
+
+    towers[3].health = 0;
+    assert_eq!(sim::tally_towers(&towers, &LAYOUT_1V1).result(), Some(0));
+}
+
+#[test]
+fn clock_moves_through_regulation_and_overtime() {
+    let clock = sim::MatchClock { started_at: 1_000, regulation_seconds: 180, overtime_seconds: 60 };
+
+    assert_eq!(clock.phase(1_000), sim::MatchPhase::Regulation);
+    assert_eq!(clock.phase(1_179), sim::MatchPhase::Regulation);
+    assert_eq!(clock.phase(1_180), sim::MatchPhase::Overtime);
+    assert_eq!(clock.phase(1_240), sim::MatchPhase::Finished);
+}
+
+#[test]
+fn lobby_opens_to_anyone_exactly_at_the_timeout() {
+    assert!(!sim::lobby_expired(1_000, 1_000));
+    assert!(!sim::lobby_expired(1_000, 1_000 + LOBBY_TIMEOUT_SECONDS - 1));
+    assert!(sim::lobby_expired(1_000, 1_000 + LOBBY_TIMEOUT_SECONDS));
+}
+
+#[test]
+fn unclaimed_battle_closes_exactly_after_the_grace_period() {
+    assert!(!sim::close_grace_over(5_000, 5_000));
+    assert!(!sim::close_grace_over(5_000, 5_000 + CLOSE_GRACE_SECONDS - 1));
+    assert!(sim::close_grace_over(5_000, 5_000 + CLOSE_GRACE_SECONDS));
+}
+
+#[test]
+fn overtime_is_sudden_death_on_princess_towers() {
+    let clock = sim::MatchClock { started_at: 0, regulation_seconds: 180, overtime_seconds: 60 };
+    let mut towers = towers();
+    towers[1].health = 100;
+    towers[3].health = 1000;
+
+    let tally = sim::tally_towers(&towers, &LAYOUT_1V1);
+    assert!(!sim::is_decided(&clock, 100, &tally));
+    assert!(!sim::is_decided(&clock, 200, &tally));
+    assert!(sim::is_decided(&clock, 240, &tally));
+    assert_eq!(tally.result(), Some(0));
+
+    towers[1].health = 0;
+    let tally = sim::tally_towers(&towers, &LAYOUT_1V1);
+    assert!(!sim::is_decided(&clock, 100, &tally));
+    assert!(sim::is_decided(&clock, 200, &tally));
+    assert_eq!(tally.result(), Some(1));
+}
+
+#[test]
+fn target_type_limits_what_troops_lock_on_to() {
+    let mut towers = towers();
+    let mut giant = troop(1, 0, 0, 5, 10_000, 100);
+    giant.targets = TargetType::Buildings;
+    let mut ground = troop(2, 0, 2, 5, 10_000, 100);
+    ground.targets = TargetType::Ground;
+    let mut dragon = troop(3, 1, 1, 6, 10_000, 100);
+    dragon.flying = true;
+    let mut entities = vec![giant, ground, dragon];
+
+    run(&mut towers, &mut entities, 1);
+
+    let tower_ids: Vec<_> = (0..6).map(sim::tower_target_id).collect();
+    assert!(tower_ids.contains(&entities[0].target_id.unwrap()));
+    assert!(tower_ids.contains(&entities[1].target_id.unwrap()));
+
+    entities[1].targets = TargetType::Air;
+    entities[1].state = EntityState::Moving;
+    run(&mut towers, &mut entities, 1);
+    assert_eq!(entities[1].target_id, Some(3));
+}
+
+#[test]
+fn spell_hits_enemies_in_radius_and_chips_towers() {
+    let mut towers = towers();
+    let mut entities = vec![
+        troop(1, 1, 10, 12, 100, 10),
+        troop(2, 1, 10, 9, 100, 10),
+        troop(3, 0, 10, 13, 100, 10),
+    ];
+    let mut destroyed = [0u8; 2];
+    let mut dealt = [0u64; 2];
+
+    sim::cast_spell(&mut towers, &mut entities, 0, 10, 13, 2, 200, &mut destroyed, &mut dealt);
+
+    assert_eq!(entities[0].state, EntityState::Dead);
+    assert_eq!(entities[1].health, 100);
+    assert_eq!(entities[2].health, 100);
+    assert_eq!(towers[5].health, 1500 - 60);
+    assert_eq!(dealt, [60, 0]);
+}
+
+#[test]
+fn deploys_are_limited_to_own_half() {
+    let towers = towers();
+
+    assert!(sim::can_deploy(&ARENA, &towers, 0, -3, -8));
+    assert!(sim::can_deploy(&ARENA, &towers, 1, 4, 8));
+    assert!(!sim::can_deploy(&ARENA, &towers, 0, 0, 0));
+    assert!(!sim::can_deploy(&ARENA, &towers, 0, 5, 6));
+    assert!(!sim::can_deploy(&ARENA, &towers, 1, 5, -6));
+    assert!(!sim::can_deploy(&ARENA, &towers, 0, 15, -8));
+    assert!(!sim::can_deploy(&ARENA, &towers, 0, 0, -20));
+}
+
+#[test]
+fn fallen_princess_tower_opens_pocket_on_its_lane() {
+    let mut towers = towers();
+    towers[4].health = 0;
+
+    assert!(sim::can_deploy(&ARENA, &towers, 0, -6, 2));
+    assert!(sim::can_deploy(&ARENA, &towers, 0, -10, 5));
+    assert!(!sim::can_deploy(&ARENA, &towers, 0, 6, 2));
+    assert!(!sim::can_deploy(&ARENA, &towers, 0, -6, 6));
+    assert!(!sim::can_deploy(&ARENA, &towers, 1, -6, -2));
+}
+
+#[test]
+fn default_arena_matches_the_client_grid() {
+    let arena = sim::DEFAULT_ARENA;
+    assert_eq!((arena.max_x - arena.min_x + 1, arena.max_y - arena.min_y + 1), (18, 30));
+    assert_eq!(arena.river_max_y - arena.river_min_y + 1, 2);
+    assert!(sim::arena_is_valid(&arena));
+
+    // Both halves hold the same number of rows and the towers mirror across the river.
+    assert_eq!(arena.river_min_y - arena.min_y, arena.max_y - arena.river_max_y);
+    for (own, enemy) in arena.towers[..3].iter().zip(&arena.towers[3..]) {
+        assert_eq!((own.x, own.y), (enemy.x, arena.river_min_y + arena.river_max_y - enemy.y));
+    }
+
+    let towers = LAYOUT_1V1.initial_towers(&arena);
+    assert!(towers.iter().all(|t| !sim::can_deploy(&arena, &towers, t.owner_idx, t.x, t.y)));
+}
+
+#[test]
+fn arena_rejects_towers_off_the_grid_or_on_the_river() {
+    assert!(sim::arena_is_valid(&ARENA));
+
+    let mut on_river = ARENA;
+    on_river.towers[4].y = 2;
+    assert!(!sim::arena_is_valid(&on_river));
+
+    let mut off_grid = ARENA;
+    off_grid.towers[0].y = -21;
+    assert!(!sim::arena_is_valid(&off_grid));
+}
+
+fn cannon(id: u32, owner_idx: u8, x: i32, y: i32, health: i32, lifetime: u16) -> Entity {
+    Entity {
+        state: EntityState::Idle,
+        speed: 0,
+        range: 5,
+        kind: CardKind::Building,
+        lifetime,
+        ..troop(id, owner_idx, x, y, health, 60)
+    }
+}
+
+#[test]
+fn buildings_decay_over_their_lifetime_without_moving() {
+    let mut towers = towers();
+    let mut entities = vec![cannon(1, 0, 0, -8, 100, 4)];
+
+    run(&mut towers, &mut entities, 3);
+    assert_eq!((entities[0].x, entities[0].y), (0, -8));
+    assert_eq!(entities[0].health, 25);
+    assert_eq!(entities[0].state, EntityState::Idle);
+
+    run(&mut towers, &mut entities, 1);
+    assert_eq!(entities[0].state, EntityState::Dead);
+}
+
+#[test]
+fn building_targeting_troops_are_drawn_to_buildings() {
+    let mut towers = towers();
+    let mut giant = troop(1, 1, 0, -3, 10_000, 100);
+    giant.targets = TargetType::Buildings;
+    let mut entities = vec![giant, troop(2, 0, 2, -4, 10_000, 10), cannon(3, 0, -2, -7, 1_000, 30)];
+
+    run(&mut towers, &mut entities, 1);
+
+    assert_eq!(entities[0].target_id, Some(3));
+}
+
+#[test]
+fn formation_spawns_every_unit_mirrored_per_side() {
+    let mut formation = [FormationOffset::default(); MAX_UNITS_PER_CARD];
+    formation[0] = FormationOffset { dx: -1, dy: 0 };
+    formation[1] = FormationOffset { dx: 1, dy: 0 };
+    formation[2] = FormationOffset { dx: 0, dy: 2 };
+    let barbarians = CardStats {
+        card_id: 9,
+        cost: 5,
+        health: 500,
+        damage: 150,
+        hit_speed: 1,
+        range: 1,
+        speed: 1,
+        targets: TargetType::Ground,
+        flying: false,
+        kind: CardKind::Troop,
+        lifetime: 0,
+        unit_count: 3,
+        formation,
+    };
+
+    assert_eq!(sim::unit_positions(&barbarians, 0, 0, -10), [(-1, -10), (1, -10), (0, -8)]);
+    assert_eq!(sim::unit_positions(&barbarians, 1, 0, 10), [(-1, 10), (1, 10), (0, 8)]);
+
+    // Units pushed past the edge are not clamped onto a shared tile; the deploy is refused.
+    let at_edge = sim::unit_positions(&barbarians, 0, 14, -20);
+    assert_eq!(at_edge[1], (15, -20));
+    assert!(!sim::can_deploy_all(&ARENA, &towers(), 0, &at_edge));
+
+    let scaled = sim::scale_stats(&barbarians, 1);
+    let unit = sim::spawn_entity(&barbarians, &scaled, 7, 1, 3, 4);
+    assert_eq!((unit.id, unit.owner_idx, unit.card_id, unit.health), (7, 1, 9, 500));
+}
+
+#[test]
+fn formation_reaching_the_river_is_refused_whole() {
+    let mut formation = [FormationOffset::default(); MAX_UNITS_PER_CARD];
+    formation[1] = FormationOffset { dx: 0, dy: 2 };
+    let pair = CardStats {
+        card_id: 9,
+        cost: 3,
+        health: 300,
+        damage: 80,
+        hit_speed: 1,
+        range: 1,
+        speed: 1,
+        targets: TargetType::Ground,
+        flying: false,
+        kind: CardKind::Troop,
+        lifetime: 0,
+        unit_count: 2,
+        formation,
+    };
+    let towers = towers();
+
+    // The deploy tile is fine for either side, but the second unit would land on the river.
+    let near_river = sim::unit_positions(&pair, 0, 3, -2);
+    assert!(sim::can_deploy(&ARENA, &towers, 0, 3, -2));
+    assert!(!sim::can_deploy_all(&ARENA, &towers, 0, &near_river));
+    let near_river = sim::unit_positions(&pair, 1, 3, 2);
+    assert!(!sim::can_deploy_all(&ARENA, &towers, 1, &near_river));
+
+    let back = sim::unit_positions(&pair, 0, 3, -4);
+    assert!(sim::can_deploy_all(&ARENA, &towers, 0, &back));
+}
+
+#[test]
+fn dead_entities_are_compacted_in_order() {
+    let mut entities = vec![troop(1, 0, 0, -5, 100, 10), troop(2, 1, 0, 5, 100, 10), troop(3, 0, 3, -5, 100, 10)];
+    entities[0].state = EntityState::Dead;
+
+    sim::remove_dead(&mut entities);
+
+    assert_eq!(entities.iter().map(|e| e.id).collect::<Vec<_>>(), [2, 3]);
+}
+
+#[test]
+fn queue_skips_empty_slots_and_shuffles_deterministically() {
+    let queue = sim::init_queue(&[1, 2, 0, 4, 5, 0, 7, 8]);
+    assert_eq!(queue, [0, 1, 3, 4, 6, 7, EMPTY_QUEUE_SLOT, EMPTY_QUEUE_SLOT]);
+
+    let seed = sim::mix_seed(&[7u8; 32], 42);
+    let (mut a, mut b) = (queue, queue);
+    sim::shuffle_queue(&mut a, sim::player_seed(&seed, 0));
+    sim::shuffle_queue(&mut b, sim::player_seed(&seed, 0));
+
+    assert_eq!(a, b);
+    assert_eq!(&a[6..], [EMPTY_QUEUE_SLOT, EMPTY_QUEUE_SLOT]);
+    let mut sorted = a[..6].to_vec();
+    sorted.sort();
+    assert_eq!(sorted, [0, 1, 3, 4, 6, 7]);
+}
+
+fn slot_hashes(slots: &[u64]) -> Vec<u8> {
+    slots.iter().flat_map(|&slot| [slot.to_le_bytes().to_vec(), vec![slot as u8; 32]].concat()).collect()
+}
+
+#[test]
+fn seed_comes_from_the_oldest_slot_at_or_after_the_seed_slot() {
+    // Newest first, as in the sysvar; slot 102 was skipped
+    let entries = slot_hashes(&[105, 104, 103, 101, 100]);
+
+    assert_eq!(sim::find_slot_hash(&entries, 101), Some((101, [101u8; 32])));
+    assert_eq!(sim::find_slot_hash(&entries, 102), Some((103, [103u8; 32])));
+    assert_eq!(sim::find_slot_hash(&entries, 106), None);
+    assert_eq!(sim::find_slot_hash(&[], 100), None);
+}
+
+#[test]
+fn playing_a_card_cycles_it_to_the_back() {
+    let mut queue = sim::init_queue(&[1, 2, 3, 4, 5, 6, 0, 0]);
+    assert_eq!(sim::hand(&queue), [0, 1, 2, 3]);
+
+    assert!(!sim::play_from_hand(&mut queue, 4));
+    assert!(sim::play_from_hand(&mut queue, 1));
+
+    assert_eq!(sim::hand(&queue), [0, 2, 3, 4]);
+    assert_eq!(queue, [0, 2, 3, 4, 5, 1, EMPTY_QUEUE_SLOT, EMPTY_QUEUE_SLOT]);
+}
+
+fn profile(deck: [u8; DECK_SIZE], inventory: Vec<CardProgress>, trophies: u32) -> PlayerProfile {
+    PlayerProfile {
+        authority: Default::default(),
+        mmr: 1000,
+        deck,
+        inventory,
+        username: String::new(),
+        trophies,
+        road_claimed: 0,
+        season: 0,
+        season_best_trophies: 0,
+        best_season: 0,
+        best_season_trophies: 0,
+        pending_season_reward: 0,
+        created_games: Vec::new(),
+        active_game: None,
+    }
+}
+
+#[test]
+fn snapshot_copies_owned_deck_cards() {
+    // Card 9 is in the deck but no longer owned; the last five slots are empty.
+    let profile = profile(
+        [1, 2, 9, 0, 0, 0, 0, 0],
+        vec![
+            CardProgress { card_id: 1, level: 11, xp: 0, amount: 1 },
+            CardProgress { card_id: 2, level: 3, xp: 0, amount: 1 },
+        ],
+        0,
+    );
+
+    let snapshot = profile.deck_snapshot(LevelRule::Standard);
+    assert_eq!(snapshot[0], DeckCard { card_id: 1, level: 11 });
+    assert_eq!(snapshot[1], DeckCard { card_id: 2, level: 3 });
+    assert!(snapshot[2..].iter().all(|c| *c == DeckCard::default()));
+}
+
+#[test]
+fn level_rule_caps_or_fixes_snapshot_levels() {
+    let profile = profile(
+        [1, 2, 9, 0, 0, 0, 0, 0],
+        vec![
+            CardProgress { card_id: 1, level: 11, xp: 0, amount: 1 },
+            CardProgress { card_id: 2, level: 3, xp: 0, amount: 1 },
+        ],
+        0,
+    );
+
+    let levels = |rule| profile.deck_snapshot(rule).map(|c| c.level);
+    assert_eq!(levels(LevelRule::Standard)[..3], [11, 3, 0]);
+    assert_eq!(levels(LevelRule::Cap(9))[..3], [9, 3, 0]);
+    assert_eq!(levels(LevelRule::Fixed(9))[..3], [9, 9, 0]);
+
+    assert!(!LevelRule::Cap(0).is_valid());
+    assert!(!LevelRule::Fixed(MAX_CARD_LEVEL + 1).is_valid());
+}
+
+#[test]
+fn idle_side_needs_the_whole_side_idle() {
+    assert_eq!(sim::idle_side(&LAYOUT_1V1, &[0, 0], 59, 60), None);
+    assert_eq!(sim::idle_side(&LAYOUT_1V1, &[0, 30], 60, 60), Some(0));
+    assert_eq!(sim::idle_side(&LAYOUT_1V1, &[0, 0], 60, 60), None);
+
+    // One teammate still acting keeps the team in the game.
+    assert_eq!(sim::idle_side(&LAYOUT_2V2, &[0, 50, 90, 0], 100, 60), None);
+    assert_eq!(sim::idle_side(&LAYOUT_2V2, &[0, 50, 0, 90], 120, 60), Some(0));
+}
+
+#[test]
+fn surrender_forfeits_a_1v1_at_once() {
+    let clock = sim::MatchClock { started_at: 0, regulation_seconds: 180, overtime_seconds: 60 };
+    let tally = sim::tally_towers(&towers(), &LAYOUT_1V1);
+
+    let outcome = sim::surrender_outcome(&LAYOUT_1V1, &clock, 100, &tally, &[false; 2], 1);
+    assert_eq!(outcome, sim::SurrenderOutcome::Forfeit(0));
+}
+
+#[test]
+fn a_2v2_team_forfeits_once_both_teammates_leave() {
+    let clock = sim::MatchClock { started_at: 0, regulation_seconds: 180, overtime_seconds: 60 };
+    let tally = sim::tally_towers(&LAYOUT_2V2.initial_towers(&ARENA), &LAYOUT_2V2);
+
+    // Player 3 leaving alone keeps team 1 in the game, even with player 0 gone.
+    let outcome = sim::surrender_outcome(&LAYOUT_2V2, &clock, 100, &tally, &[true, false, false, false], 3);
+    assert_eq!(outcome, sim::SurrenderOutcome::Left);
+
+    let outcome = sim::surrender_outcome(&LAYOUT_2V2, &clock, 100, &tally, &[false, false, true, false], 3);
+    assert_eq!(outcome, sim::SurrenderOutcome::Forfeit(0));
+}
+
+#[test]
+fn surrender_after_the_clock_decided_keeps_the_result() {
+    let clock = sim::MatchClock { started_at: 0, regulation_seconds: 180, overtime_seconds: 60 };
+    let mut towers = towers();
+    towers[4].health = 0;
+    let tally = sim::tally_towers(&towers, &LAYOUT_1V1);
+
+    // Side 0 leads on towers when regulation ends, so its surrender doesn't hand over the win.
+    let outcome = sim::surrender_outcome(&LAYOUT_1V1, &clock, 180, &tally, &[false; 2], 0);
+    assert_eq!(outcome, sim::SurrenderOutcome::Decided(Some(0)));
+    assert_eq!(sim::surrender_outcome(&LAYOUT_1V1, &clock, 179, &tally, &[false; 2], 0), sim::SurrenderOutcome::Forfeit(1));
+}
+
+#[test]
+fn even_match_moves_ratings_symmetrically() {
+    assert_eq!(sim::expected_score(1000, 1000), 500);
+    assert_eq!(sim::expected_score(1400, 1000), 909);
+    assert_eq!(sim::expected_score(1000, 1400), 91);
+
+    let win = sim::rating_change(1000, 1000, sim::SCORE_WIN);
+    let loss = sim::rating_change(1000, 1000, sim::SCORE_LOSS);
+    assert_eq!(win, sim::RatingChange { mmr: 16, trophies: 30 });
+    assert_eq!(loss, sim::RatingChange { mmr: -16, trophies: -30 });
+    assert_eq!(sim::rating_change(1000, 1000, sim::SCORE_DRAW), sim::RatingChange { mmr: 0, trophies: 0 });
+
+    // Beating a stronger opponent is worth more; a draw against one still gains.
+    assert!(sim::rating_change(1000, 1200, sim::SCORE_WIN).trophies > win.trophies);
+    assert!(sim::rating_change(1000, 1200, sim::SCORE_DRAW).mmr > 0);
+}
+
+#[test]
+fn trophy_losses_stop_at_the_arena_floor() {
+    let loss = sim::RatingChange { mmr: -16, trophies: -30 };
+    assert_eq!(sim::apply_rating_change(1000, 310, loss), (984, 300));
+    assert_eq!(sim::apply_rating_change(10, 20, loss), (0, 0));
+    assert_eq!(sim::apply_rating_change(1000, 350, loss), (984, 320));
+    assert_eq!(sim::team_rating(&[1000, 1201]), 1100);
+}
+
+#[test]
+fn settlement_rates_floors_and_rewards_each_player() {
+    let settle = |mmr, trophies, reward| sim::Settlement { mmr, trophies, reward };
+
+    let result = sim::settle(&LAYOUT_1V1, Some(1), &[(1000, 310), (1000, 290)]);
+    assert_eq!(result, [settle(984, 300, 0), settle(1016, 320, TOKEN_REWARD_AMOUNT)]);
+
+    let result = sim::settle(&LAYOUT_1V1, None, &[(1000, 310), (1000, 290)]);
+    assert_eq!(result, [settle(1000, 310, 0), settle(1000, 290, 0)]);
+
+    // Team 1 (average 1000) beats team 0 (average 1100); rewards follow each winner's
+    // pre-match arena.
+    let result = sim::settle(&LAYOUT_2V2, Some(1), &[(1000, 0), (1200, 0), (1000, 600), (1000, 0)]);
+    assert_eq!(
+        result,
+        [settle(980, 0, 0), settle(1180, 0, 0), settle(1020, 638, sim::battle_reward(600)), settle(1020, 38, TOKEN_REWARD_AMOUNT)]
+    );
+}
+
+#[test]
+fn arenas_gate_unlocks_and_scale_rewards() {
+    assert_eq!(sim::arena_index(0), 0);
+    assert_eq!(sim::arena_index(299), 0);
+    assert_eq!(sim::arena_index(300), 1);
+    assert_eq!(sim::arena_index(9999), sim::ARENAS.len() - 1);
+
+    assert!(sim::can_unlock(0, 1));
+    assert!(sim::can_unlock(0, 60));
+    assert!(!sim::can_unlock(299, 7));
+    assert!(sim::can_unlock(300, 7));
+
+    assert_eq!(sim::battle_reward(0), TOKEN_REWARD_AMOUNT);
+    assert!(sim::battle_reward(3000) > sim::battle_reward(300));
+}
+
+#[test]
+fn season_rollover_banks_reward_and_soft_resets() {
+    let mut p = profile([0; DECK_SIZE], Vec::new(), 0);
+    p.roll_season(1);
+    assert_eq!((p.season, p.trophies, p.pending_season_reward), (1, 0, 0));
+
+    p.set_trophies(3400);
+    p.set_trophies(3200);
+    assert_eq!(p.season_best_trophies, 3400);
+
+    p.roll_season(2);
+    assert_eq!(p.trophies, 3100);
+    assert_eq!(p.season_best_trophies, 3100);
+    assert_eq!(p.pending_season_reward, sim::season_reward(3400));
+    assert_eq!((p.best_season, p.best_season_trophies), (1, 3400));
+
+    // Rolling again within the same season changes nothing.
+    p.roll_season(2);
+    assert_eq!(p.trophies, 3100);
+    assert_eq!(p.pending_season_reward, sim::season_reward(3400));
+}
+
+#[test]
+fn legacy_profile_migrates_with_default_progress() {
+    let legacy = LegacyPlayerProfile {
+        authority: Pubkey::new_unique(),
+        mmr: 1200,
+        deck: [1, 2, 3, 4, 0, 0, 0, 0],
+        inventory: vec![CardProgress { card_id: 1, level: 3, xp: 10, amount: 2 }],
+        username: "old".to_string(),
+        trophies: 450,
+    };
+    // Old accounts were allocated at full size, so anything past the content is padding.
+    let mut data = legacy.try_to_vec().unwrap();
+    data.resize(LegacyPlayerProfile::INIT_SPACE, 0xff);
+
+    let p = PlayerProfile::from(LegacyPlayerProfile::deserialize(&mut &data[..]).unwrap());
+    assert_eq!((p.authority, p.mmr, p.trophies, p.username.as_str()), (legacy.authority, 1200, 450, "old"));
+    assert_eq!(p.deck_snapshot(LevelRule::Standard)[0], DeckCard { card_id: 1, level: 3 });
+    assert_eq!((p.road_claimed, p.season, p.pending_season_reward), (0, 0, 0));
+    assert!(p.created_games.is_empty() && p.active_game.is_none());
+}
+
+#[test]
+fn first_season_joins_without_reward_or_reset() {
+    let mut p = profile([0; DECK_SIZE], Vec::new(), 3400);
+    p.roll_season(3);
+
+    assert_eq!((p.season, p.trophies, p.season_best_trophies), (3, 3400, 3400));
+    assert_eq!(p.pending_season_reward, 0);
+    assert_eq!((p.best_season, p.best_season_trophies), (0, 0));
+}
+
+#[test]
+fn missed_seasons_each_soft_reset_but_pay_once() {
+    let mut p = profile([0; DECK_SIZE], Vec::new(), 0);
+    p.roll_season(1);
+    p.set_trophies(3800);
+
+    // Seasons 1 and 2 both end: 3800 -> 3400 -> 3200.
+    p.roll_season(3);
+    assert_eq!((p.season, p.trophies), (3, 3200));
+    assert_eq!(p.pending_season_reward, sim::season_reward(3800));
+    assert_eq!((p.best_season, p.best_season_trophies), (1, 3800));
+
+    // Long absences stop at the threshold instead of looping through every season.
+    p.roll_season(u32::MAX);
+    assert_eq!(p.trophies, SEASON_RESET_THRESHOLD);
+}
+
+fn queued(mmr: u32, enqueued_at: i64) -> QueuedPlayer {
+    QueuedPlayer { mmr, enqueued_at }
+}
+
+#[test]
+fn match_window_widens_while_waiting() {
+    let entries = [queued(1000, 0), queued(1200, 0), queued(1030, 0)];
+    assert_eq!(sim::find_match(&entries, 0, 2), Some(vec![0, 2]));
+
+    // 200 apart: only once both windows have grown past the gap.
+    let entries = [queued(1000, 0), queued(1200, 20)];
+    assert_eq!(sim::find_match(&entries, 49, 2), None);
+    assert_eq!(sim::find_match(&entries, 50, 2), Some(vec![0, 1]));
+    assert_eq!(sim::match_window(i64::MAX / 8), MATCH_WINDOW_MAX);
+}
+
+#[test]
+fn four_players_split_into_balanced_teams() {
+    let entries = [queued(1000, 0), queued(1040, 0), queued(1010, 0), queued(1500, 0), queued(1020, 0)];
+    let group = sim::find_match(&entries, 0, 4).unwrap();
+    assert_eq!(group, vec![0, 2, 4, 1]);
+
+    // 955 and 1045 are each within 1000's window but 90 apart from one another.
+    let entries = [queued(1000, 0), queued(955, 0), queued(1045, 0), queued(1010, 0)];
+    assert_eq!(sim::find_match(&entries, 0, 4), None);
+    assert_eq!(sim::find_match(&entries, 8, 4), Some(vec![0, 3, 1, 2]));
+
+    let mmrs = [1000, 1300, 1100, 1200];
+    let slots = sim::balance_teams(mmrs);
+    assert_eq!(slots, [0, 1, 2, 3]);
+    assert_eq!(mmrs[slots[0]] + mmrs[slots[1]], mmrs[slots[2]] + mmrs[slots[3]]);
+}
+
+#[test]
+fn counter_ids_are_sequential_and_creators_keep_the_latest() {
+    let mut counter = GameCounter { next_game_id: 7, bump: 0 };
+    let mut p = profile([0; DECK_SIZE], Vec::new(), 0);
+    for _ in 0..MAX_CREATED_GAMES + 2 {
+        let game_id = counter.take_id();
+        p.record_created_game(GameRef { game_id, team_size: 1 });
+    }
+
+    assert_eq!(counter.next_game_id, 7 + MAX_CREATED_GAMES as u64 + 2);
+    assert_eq!(p.created_games.len(), MAX_CREATED_GAMES);
+    assert_eq!(p.created_games[0].game_id, 9);
+    assert_eq!(p.created_games.last().unwrap().game_id, counter.next_game_id - 1);
+}
+
+#[test]
+fn invite_list_fills_every_open_seat_with_someone_else() {
+    let creator = Pubkey::new_unique();
+    let (a, b, c) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+
+    assert!(state::invites_valid(&[], creator, 2));
+    assert!(state::invites_valid(&[a], creator, 2));
+    assert!(state::invites_valid(&[a, b, c], creator, 4));
+
+    assert!(!state::invites_valid(&[a, b], creator, 2));
+    assert!(!state::invites_valid(&[a, b], creator, 4));
+    assert!(!state::invites_valid(&[creator], creator, 2));
+    assert!(!state::invites_valid(&[a, b, a], creator, 4));
+    assert!(!state::invites_valid(&[Pubkey::default()], creator, 2));
+}
+
+#[test]
+fn private_lobby_admits_only_invited_players() {
+    let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    assert!(state::admits(&[], a));
+    assert!(state::admits(&[a], a));
+    assert!(!state::admits(&[a], b));
+    assert!(!state::admits(&[a], Pubkey::default()));
+}
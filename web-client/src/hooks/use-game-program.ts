@@ -76,6 +76,38 @@ const getRequestPda = (
     )[0];
 };
 
+// Helper: derive the game counter PDA. Its nextGameId is the id the next created
+// game gets, 1v1 or 2v2, and the battle PDA has to be derived from it.
+const getGameCounterPda = (programId: PublicKey): PublicKey => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("game_counter")],
+        programId
+    )[0];
+};
+
+// How many ids createGame tries when other creates keep taking the one it read.
+const CREATE_GAME_ATTEMPTS = 3;
+
+// Helper: derive the match queue PDA for a team size (1 = 1v1, 2 = 2v2)
+const getMatchQueuePda = (teamSize: number, programId: PublicKey): PublicKey => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("match_queue"), Buffer.from([teamSize])],
+        programId
+    )[0];
+};
+
+// Helper: the battle a profile's activeGame points at, or null. Creating or joining
+// a game takes it so the program can check that battle is over first.
+const getActiveBattlePda = (
+    activeGame: { gameId: BN; teamSize: number } | null,
+    programId: PublicKey
+): PublicKey | null => {
+    if (!activeGame) return null;
+    return activeGame.teamSize === 1
+        ? getBattlePda(activeGame.gameId, programId)
+        : getBattle2v2Pda(activeGame.gameId, programId);
+};
+
 export type DelegationStatus = "undelegated" | "delegated" | "checking";
 
 export interface CardProgress {
@@ -228,24 +260,46 @@ export function useGameProgram() {
     );
 
     // ─── Create Game (Base Layer) ─────────────────────────────────────────────
-    // Player One creates a game with a given gameId. The battle PDA is derived
-    // from the gameId seed automatically by Anchor.
+    // Player One creates a game under the id the game counter hands out next.
+    // The battle PDA is derived from the counter's nextGameId; if another create
+    // takes that id first the transaction fails its seeds check, so the counter
+    // is re-read and the next id tried. Resolves to the id the game got.
 
     const createGame = useCallback(
-        async (gameId: BN): Promise<string> => {
+        async (
+            levelRule: any = { standard: {} },
+            invited: PublicKey[] = [],
+            friendly = false
+        ): Promise<{ tx: string; gameId: BN }> => {
             if (!program || !wallet.publicKey) throw new Error("Wallet not connected");
             setIsLoading(true);
             try {
-                const tx = await program.methods
-                    .createGame(gameId)
-                    .accounts({
-                        battle: getBattlePda(gameId, program.programId),
-                        playerOneProfile: getPlayerProfilePda(wallet.publicKey, program.programId),
-                        playerOne: wallet.publicKey,
-                        systemProgram: SystemProgram.programId,
-                    } as any)
-                    .rpc();
-                return tx;
+                const gameCounter = getGameCounterPda(program.programId);
+                const profile = getPlayerProfilePda(wallet.publicKey, program.programId);
+                const { activeGame } = await program.account.playerProfile.fetch(profile);
+                for (let attempt = 1; ; attempt++) {
+                    const { nextGameId: gameId } = await program.account.gameCounter.fetch(gameCounter);
+                    try {
+                        const tx = await program.methods
+                            .createGame(levelRule, invited, friendly)
+                            .accounts({
+                                gameCounter,
+                                battle: getBattlePda(gameId, program.programId),
+                                playerOneProfile: profile,
+                                queue1v1: getMatchQueuePda(1, program.programId),
+                                queue2v2: getMatchQueuePda(2, program.programId),
+                                activeBattle: getActiveBattlePda(activeGame, program.programId),
+                                playerOne: wallet.publicKey,
+                                systemProgram: SystemProgram.programId,
+                            } as any)
+                            .rpc();
+                        return { tx, gameId };
+                    } catch (err: any) {
+                        const { nextGameId } = await program.account.gameCounter.fetch(gameCounter);
+                        if (nextGameId.eq(gameId) || attempt >= CREATE_GAME_ATTEMPTS) throw err;
+                        console.warn(`[createGame] Game id ${gameId.toString()} was taken, retrying`);
+                    }
+                }
             } catch (err: any) {
                 setError(err.message);
                 throw err;
@@ -264,11 +318,16 @@ export function useGameProgram() {
             if (!program || !wallet.publicKey) throw new Error("Wallet not connected");
             setIsLoading(true);
             try {
+                const profile = getPlayerProfilePda(wallet.publicKey, program.programId);
+                const { activeGame } = await program.account.playerProfile.fetch(profile);
                 const tx = await program.methods
                     .joinGame(gameId)
                     .accounts({
                         battle: getBattlePda(gameId, program.programId),
-                        playerTwoProfile: getPlayerProfilePda(wallet.publicKey, program.programId),
+                        playerTwoProfile: profile,
+                        queue1v1: getMatchQueuePda(1, program.programId),
+                        queue2v2: getMatchQueuePda(2, program.programId),
+                        activeBattle: getActiveBattlePda(activeGame, program.programId),
                         playerTwo: wallet.publicKey,
                     } as any)
                     .rpc();
@@ -287,20 +346,40 @@ export function useGameProgram() {
     // ─── Create Game 2v2 (Base Layer) ─────────────────────────────────────────
 
     const createGame2v2 = useCallback(
-        async (gameId: BN): Promise<string> => {
+        async (
+            levelRule: any = { standard: {} },
+            invited: PublicKey[] = [],
+            friendly = false
+        ): Promise<{ tx: string; gameId: BN }> => {
             if (!program || !wallet.publicKey) throw new Error("Wallet not connected");
             setIsLoading(true);
             try {
-                const tx = await program.methods
-                    .createGame2v2(gameId)
-                    .accounts({
-                        battle: getBattle2v2Pda(gameId, program.programId),
-                        playerOneProfile: getPlayerProfilePda(wallet.publicKey, program.programId),
-                        playerOne: wallet.publicKey,
-                        systemProgram: SystemProgram.programId,
-                    } as any)
-                    .rpc();
-                return tx;
+                const gameCounter = getGameCounterPda(program.programId);
+                const profile = getPlayerProfilePda(wallet.publicKey, program.programId);
+                const { activeGame } = await program.account.playerProfile.fetch(profile);
+                for (let attempt = 1; ; attempt++) {
+                    const { nextGameId: gameId } = await program.account.gameCounter.fetch(gameCounter);
+                    try {
+                        const tx = await program.methods
+                            .createGame2v2(levelRule, invited, friendly)
+                            .accounts({
+                                gameCounter,
+                                battle: getBattle2v2Pda(gameId, program.programId),
+                                playerOneProfile: profile,
+                                queue1v1: getMatchQueuePda(1, program.programId),
+                                queue2v2: getMatchQueuePda(2, program.programId),
+                                activeBattle: getActiveBattlePda(activeGame, program.programId),
+                                playerOne: wallet.publicKey,
+                                systemProgram: SystemProgram.programId,
+                            } as any)
+                            .rpc();
+                        return { tx, gameId };
+                    } catch (err: any) {
+                        const { nextGameId } = await program.account.gameCounter.fetch(gameCounter);
+                        if (nextGameId.eq(gameId) || attempt >= CREATE_GAME_ATTEMPTS) throw err;
+                        console.warn(`[createGame2v2] Game id ${gameId.toString()} was taken, retrying`);
+                    }
+                }
             } catch (err: any) {
                 setError(err.message);
                 throw err;
@@ -318,11 +397,16 @@ export function useGameProgram() {
             if (!program || !wallet.publicKey) throw new Error("Wallet not connected");
             setIsLoading(true);
             try {
+                const profile = getPlayerProfilePda(wallet.publicKey, program.programId);
+                const { activeGame } = await program.account.playerProfile.fetch(profile);
                 const tx = await program.methods
                     .joinGame2v2(gameId)
                     .accounts({
                         battle: getBattle2v2Pda(gameId, program.programId),
-                        playerProfile: getPlayerProfilePda(wallet.publicKey, program.programId),
+                        playerProfile: profile,
+                        queue1v1: getMatchQueuePda(1, program.programId),
+                        queue2v2: getMatchQueuePda(2, program.programId),
+                        activeBattle: getActiveBattlePda(activeGame, program.programId),
                         player: wallet.publicKey,
                     } as any)
                     .rpc();
@@ -752,47 +836,60 @@ export function useGameProgram() {
         [erProgram, program, wallet.publicKey]
     );
 
-    // ─── Mint Trophies (Base Layer) ───────────────────────────────────────────
-    // Called after endGame has committed back to base. Mints trophy tokens
-    // for the calling player if they were the winner.
+    // ─── Settle Battle (Base Layer) ──────────────────────────────────────────
+    // Called after the battle has been committed back to base. Applies rating
+    // and trophy changes for every player and mints the token reward to the
+    // winners. Anyone may send it; missing token accounts are created first.
 
-    const mintTrophies = useCallback(
+    const settleBattle = useCallback(
         async (gameId: BN, mint: PublicKey): Promise<string> => {
             if (!program || !wallet.publicKey) throw new Error("Wallet not connected");
             setIsLoading(true);
             try {
-                const destination = getAssociatedTokenAddressSync(mint, wallet.publicKey);
+                const battle = getBattlePda(gameId, program.programId);
+                const { players } = await program.account.battleState.fetch(battle);
 
                 const preInstructions = [];
-                const destInfo = await connection.getAccountInfo(destination);
-                if (!destInfo) {
-                    preInstructions.push(
-                        createAssociatedTokenAccountInstruction(
-                            wallet.publicKey,
-                            destination,
-                            wallet.publicKey,
-                            mint
-                        )
-                    );
+                const tokens: PublicKey[] = [];
+                for (const player of players) {
+                    const destination = getAssociatedTokenAddressSync(mint, player);
+                    tokens.push(destination);
+                    const destInfo = await connection.getAccountInfo(destination);
+                    if (!destInfo) {
+                        preInstructions.push(
+                            createAssociatedTokenAccountInstruction(
+                                wallet.publicKey,
+                                destination,
+                                player,
+                                mint
+                            )
+                        );
+                    }
                 }
 
                 const tx = await program.methods
-                    .mintTrophies(gameId)
+                    .settleBattle(gameId)
                     .accounts({
-                        battle: getBattlePda(gameId, program.programId),
-                        profile: getPlayerProfilePda(wallet.publicKey, program.programId),
+                        battle,
+                        season: PublicKey.findProgramAddressSync(
+                            [Buffer.from("season")],
+                            program.programId
+                        )[0],
+                        playerOneProfile: getPlayerProfilePda(players[0], program.programId),
+                        playerTwoProfile: getPlayerProfilePda(players[1], program.programId),
+                        playerOneTokens: tokens[0],
+                        playerTwoTokens: tokens[1],
                         mint,
-                        destination,
                         mintAuthority: PublicKey.findProgramAddressSync(
                             [Buffer.from("mint_authority")],
                             program.programId
                         )[0],
                         tokenProgram: TOKEN_PROGRAM_ID,
-                        signer: wallet.publicKey,
+                        caller: wallet.publicKey,
                     } as any)
                     .preInstructions(preInstructions)
                     .rpc();
-                console.log(`[mintTrophies] Transaction Hash: https://solscan.io/tx/${tx}?cluster=custom&customUrl=https%3A%2F%2Fdevnet.magicblock.app`);
+                console.log(`[settleBattle] Transaction Hash: https://solscan.io/tx/${tx}?cluster=custom&customUrl=https%3A%2F%2Fdevnet.magicblock.app`);
                 return tx;
             } catch (err: any) {
                 setError(err.message);
@@ -804,45 +901,61 @@ export function useGameProgram() {
         [program, wallet.publicKey, connection]
     );
 
-    // ─── Mint Trophies 2v2 (Base Layer) ───────────────────────────────────────
+    // ─── Settle Battle 2v2 (Base Layer) ──────────────────────────────────────
 
-    const mintTrophies2v2 = useCallback(
+    const settleBattle2v2 = useCallback(
         async (gameId: BN, mint: PublicKey): Promise<string> => {
             if (!program || !wallet.publicKey) throw new Error("Wallet not connected");
             setIsLoading(true);
             try {
-                const destination = getAssociatedTokenAddressSync(mint, wallet.publicKey);
+                const battle = getBattle2v2Pda(gameId, program.programId);
+                const { players } = await program.account.battleState2v2.fetch(battle);
 
                 const preInstructions = [];
-                const destInfo = await connection.getAccountInfo(destination);
-                if (!destInfo) {
-                    preInstructions.push(
-                        createAssociatedTokenAccountInstruction(
-                            wallet.publicKey,
-                            destination,
-                            wallet.publicKey,
-                            mint
-                        )
-                    );
+                const tokens: PublicKey[] = [];
+                for (const player of players) {
+                    const destination = getAssociatedTokenAddressSync(mint, player);
+                    tokens.push(destination);
+                    const destInfo = await connection.getAccountInfo(destination);
+                    if (!destInfo) {
+                        preInstructions.push(
+                            createAssociatedTokenAccountInstruction(
+                                wallet.publicKey,
+                                destination,
+                                player,
+                                mint
+                            )
+                        );
+                    }
                 }
 
                 const tx = await program.methods
-                    .mintTrophies2v2(gameId)
+                    .settleBattle2v2(gameId)
                     .accounts({
-                        battle: getBattle2v2Pda(gameId, program.programId),
-                        profile: getPlayerProfilePda(wallet.publicKey, program.programId),
+                        battle,
+                        season: PublicKey.findProgramAddressSync(
+                            [Buffer.from("season")],
+                            program.programId
+                        )[0],
+                        playerOneProfile: getPlayerProfilePda(players[0], program.programId),
+                        playerTwoProfile: getPlayerProfilePda(players[1], program.programId),
+                        playerThreeProfile: getPlayerProfilePda(players[2], program.programId),
+                        playerFourProfile: getPlayerProfilePda(players[3], program.programId),
+                        playerOneTokens: tokens[0],
+                        playerTwoTokens: tokens[1],
+                        playerThreeTokens: tokens[2],
+                        playerFourTokens: tokens[3],
                         mint,
-                        destination,
                         mintAuthority: PublicKey.findProgramAddressSync(
                             [Buffer.from("mint_authority")],
                             program.programId
                         )[0],
                         tokenProgram: TOKEN_PROGRAM_ID,
-                        signer: wallet.publicKey,
+                        caller: wallet.publicKey,
                     } as any)
                     .preInstructions(preInstructions)
                     .rpc();
-                console.log(`[mintTrophies2v2] Transaction Hash: https://solscan.io/tx/${tx}?cluster=custom&customUrl=https%3A%2F%2Fdevnet.magicblock.app`);
+                console.log(`[settleBattle2v2] Transaction Hash: https://solscan.io/tx/${tx}?cluster=custom&customUrl=https%3A%2F%2Fdevnet.magicblock.app`);
                 return tx;
             } catch (err: any) {
                 setError(err.message);
@@ -1415,8 +1528,8 @@ export function useGameProgram() {
         endGame2v2,
         commitBattle,
         commitBattle2v2,
-        mintTrophies,
-        mintTrophies2v2,
+        settleBattle,
+        settleBattle2v2,
         fetchBattleState,
         fetchBattleState2v2,
         // NFT / Resources
@@ -14,23 +14,18 @@ export type GameCore = {
   },
   "instructions": [
     {
-      "name": "commitBattle",
+      "name": "advanceTick",
       "discriminator": [
-        219,
-        116,
-        150,
-        30,
+        141,
+        62,
+        18,
         121,
-        105,
-        195,
-        23
+        9,
+        101,
+        116,
+        91
       ],
       "accounts": [
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
-        },
         {
           "name": "battle",
           "writable": true,
@@ -55,13 +50,8 @@ export type GameCore = {
           }
         },
         {
-          "name": "magicProgram",
-          "address": "Magic11111111111111111111111111111111111111"
-        },
-        {
-          "name": "magicContext",
-          "writable": true,
-          "address": "MagicContext1111111111111111111111111111111"
+          "name": "recentSlothashes",
+          "address": "SysvarS1otHashes111111111111111111111111111"
         }
       ],
       "args": [
@@ -72,23 +62,18 @@ export type GameCore = {
       ]
     },
     {
-      "name": "commitBattle2v2",
+      "name": "advanceTick2v2",
       "discriminator": [
-        40,
-        157,
-        201,
-        205,
-        225,
-        183,
-        145,
-        146
+        236,
+        170,
+        230,
+        198,
+        41,
+        162,
+        224,
+        19
       ],
       "accounts": [
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
-        },
         {
           "name": "battle",
           "writable": true,
@@ -116,13 +101,8 @@ export type GameCore = {
           }
         },
         {
-          "name": "magicProgram",
-          "address": "Magic11111111111111111111111111111111111111"
-        },
-        {
-          "name": "magicContext",
-          "writable": true,
-          "address": "MagicContext1111111111111111111111111111111"
+          "name": "recentSlothashes",
+          "address": "SysvarS1otHashes111111111111111111111111111"
         }
       ],
       "args": [
@@ -133,99 +113,68 @@ export type GameCore = {
       ]
     },
     {
-      "name": "createClan",
+      "name": "cancelGame",
       "discriminator": [
-        89,
-        254,
-        237,
-        205,
-        249,
-        101,
-        142,
-        223
+        121,
+        194,
+        154,
+        118,
+        103,
+        235,
+        149,
+        52
       ],
       "accounts": [
         {
-          "name": "clan",
+          "name": "battle",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
-                  108,
+                  98,
                   97,
-                  110
+                  116,
+                  116,
+                  108,
+                  101
                 ]
               },
               {
                 "kind": "arg",
-                "path": "name"
+                "path": "gameId"
               }
             ]
           }
         },
         {
-          "name": "clanMember",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  108,
-                  97,
-                  110,
-                  95,
-                  109,
-                  101,
-                  109,
-                  98,
-                  101,
-                  114
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "clan"
-              },
-              {
-                "kind": "account",
-                "path": "authority"
-              }
-            ]
-          }
+          "name": "payer",
+          "writable": true
         },
         {
-          "name": "authority",
-          "writable": true,
+          "name": "caller",
           "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "name",
-          "type": "string"
+          "name": "gameId",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "createGame",
+      "name": "cancelGame2v2",
       "discriminator": [
-        124,
-        69,
-        75,
-        66,
-        184,
-        220,
-        72,
-        206
+        165,
+        57,
+        121,
+        129,
+        53,
+        141,
+        237,
+        229
       ],
       "accounts": [
         {
@@ -241,7 +190,10 @@ export type GameCore = {
                   116,
                   116,
                   108,
-                  101
+                  101,
+                  50,
+                  118,
+                  50
                 ]
               },
               {
@@ -252,7 +204,37 @@ export type GameCore = {
           }
         },
         {
-          "name": "playerOneProfile",
+          "name": "payer",
+          "writable": true
+        },
+        {
+          "name": "caller",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "claimRoadReward",
+      "discriminator": [
+        68,
+        171,
+        231,
+        147,
+        176,
+        102,
+        238,
+        79
+      ],
+      "accounts": [
+        {
+          "name": "profile",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -268,69 +250,95 @@ export type GameCore = {
               },
               {
                 "kind": "account",
-                "path": "playerOne"
+                "path": "authority"
               }
             ]
           }
         },
         {
-          "name": "playerOne",
-          "writable": true,
-          "signer": true
+          "name": "mint",
+          "writable": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "userTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "mintAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "authority",
+          "signer": true
         }
       ],
       "args": [
         {
-          "name": "gameId",
-          "type": "u64"
+          "name": "milestone",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "createGame2v2",
+      "name": "claimSeasonReward",
       "discriminator": [
-        105,
-        238,
-        1,
-        45,
-        18,
-        107,
-        75,
-        99
+        129,
+        96,
+        145,
+        129,
+        154,
+        203,
+        29,
+        150
       ],
       "accounts": [
         {
-          "name": "battle",
-          "writable": true,
+          "name": "season",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  97,
-                  116,
-                  116,
-                  108,
+                  115,
                   101,
-                  50,
-                  118,
-                  50
+                  97,
+                  115,
+                  111,
+                  110
                 ]
-              },
-              {
-                "kind": "arg",
-                "path": "gameId"
               }
             ]
           }
         },
         {
-          "name": "playerOneProfile",
+          "name": "profile",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -346,178 +354,162 @@ export type GameCore = {
               },
               {
                 "kind": "account",
-                "path": "playerOne"
+                "path": "authority"
               }
             ]
           }
         },
         {
-          "name": "playerOne",
-          "writable": true,
-          "signer": true
+          "name": "mint",
+          "writable": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "gameId",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "delegateGame",
-      "discriminator": [
-        116,
-        183,
-        70,
-        107,
-        112,
-        223,
-        122,
-        210
-      ],
-      "accounts": [
-        {
-          "name": "payer",
-          "signer": true
+          "name": "userTokenAccount",
+          "writable": true
         },
         {
-          "name": "bufferPda",
-          "writable": true,
+          "name": "mintAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  97,
                   117,
-                  102,
-                  102,
-                  101,
-                  114
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "pda"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                201,
-                60,
-                163,
-                178,
-                222,
-                211,
-                170,
-                45,
-                63,
-                127,
-                183,
-                27,
-                119,
-                174,
-                113,
-                4,
-                116,
-                150,
-                226,
-                136,
-                32,
-                37,
-                213,
-                121,
-                165,
-                196,
-                103,
-                129,
-                155,
-                52,
-                15,
-                162
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "delegationRecordPda",
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimTimeout",
+      "discriminator": [
+        130,
+        234,
+        45,
+        53,
+        120,
+        90,
+        86,
+        178
+      ],
+      "accounts": [
+        {
+          "name": "battle",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  100,
-                  101,
-                  108,
-                  101,
-                  103,
+                  98,
                   97,
                   116,
-                  105,
-                  111,
-                  110
+                  116,
+                  108,
+                  101
                 ]
               },
               {
-                "kind": "account",
-                "path": "pda"
+                "kind": "arg",
+                "path": "gameId"
               }
-            ],
-            "program": {
-              "kind": "account",
-              "path": "delegationProgram"
-            }
+            ]
           }
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "delegationMetadataPda",
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "claimTimeout2v2",
+      "discriminator": [
+        247,
+        79,
+        194,
+        67,
+        242,
+        241,
+        23,
+        26
+      ],
+      "accounts": [
+        {
+          "name": "battle",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  100,
-                  101,
-                  108,
-                  101,
-                  103,
+                  98,
                   97,
                   116,
-                  105,
-                  111,
-                  110,
-                  45,
-                  109,
-                  101,
-                  116,
-                  97,
-                  100,
-                  97,
                   116,
-                  97
+                  108,
+                  101,
+                  50,
+                  118,
+                  50
                 ]
               },
               {
-                "kind": "account",
-                "path": "pda"
+                "kind": "arg",
+                "path": "gameId"
               }
-            ],
-            "program": {
-              "kind": "account",
-              "path": "delegationProgram"
-            }
+            ]
           }
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "pda",
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeBattle",
+      "discriminator": [
+        216,
+        136,
+        84,
+        48,
+        214,
+        105,
+        158,
+        51
+      ],
+      "accounts": [
+        {
+          "name": "battle",
           "writable": true,
           "pda": {
             "seeds": [
@@ -540,16 +532,12 @@ export type GameCore = {
           }
         },
         {
-          "name": "ownerProgram",
-          "address": "EYYaUKGcq2epXWsXk52P7dEXpDMZQpGdkSXVDypzDhYm"
-        },
-        {
-          "name": "delegationProgram",
-          "address": "DELeGGvXpWV2fqJUhqcF5ZSYMS4JTLjteaAMARRSaeSh"
+          "name": "payer",
+          "writable": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "caller",
+          "signer": true
         }
       ],
       "args": [
@@ -560,24 +548,20 @@ export type GameCore = {
       ]
     },
     {
-      "name": "delegateGame2v2",
+      "name": "closeBattle2v2",
       "discriminator": [
-        222,
-        184,
-        166,
-        241,
-        162,
-        5,
-        254,
-        156
+        116,
+        158,
+        120,
+        23,
+        159,
+        51,
+        2,
+        245
       ],
       "accounts": [
         {
-          "name": "payer",
-          "signer": true
-        },
-        {
-          "name": "bufferPda",
+          "name": "battle",
           "writable": true,
           "pda": {
             "seeds": [
@@ -585,130 +569,59 @@ export type GameCore = {
                 "kind": "const",
                 "value": [
                   98,
-                  117,
-                  102,
-                  102,
+                  97,
+                  116,
+                  116,
+                  108,
                   101,
-                  114
+                  50,
+                  118,
+                  50
                 ]
               },
               {
-                "kind": "account",
-                "path": "pda"
+                "kind": "arg",
+                "path": "gameId"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                201,
-                60,
-                163,
-                178,
-                222,
-                211,
-                170,
-                45,
-                63,
-                127,
-                183,
-                27,
-                119,
-                174,
-                113,
-                4,
-                116,
-                150,
-                226,
-                136,
-                32,
-                37,
-                213,
-                121,
-                165,
-                196,
-                103,
-                129,
-                155,
-                52,
-                15,
-                162
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "delegationRecordPda",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  100,
-                  101,
-                  108,
-                  101,
-                  103,
-                  97,
-                  116,
-                  105,
-                  111,
-                  110
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "pda"
-              }
-            ],
-            "program": {
-              "kind": "account",
-              "path": "delegationProgram"
-            }
-          }
+          "name": "payer",
+          "writable": true
         },
         {
-          "name": "delegationMetadataPda",
+          "name": "caller",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "commitBattle",
+      "discriminator": [
+        219,
+        116,
+        150,
+        30,
+        121,
+        105,
+        195,
+        23
+      ],
+      "accounts": [
+        {
+          "name": "payer",
           "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  100,
-                  101,
-                  108,
-                  101,
-                  103,
-                  97,
-                  116,
-                  105,
-                  111,
-                  110,
-                  45,
-                  109,
-                  101,
-                  116,
-                  97,
-                  100,
-                  97,
-                  116,
-                  97
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "pda"
-              }
-            ],
-            "program": {
-              "kind": "account",
-              "path": "delegationProgram"
-            }
-          }
+          "signer": true
         },
         {
-          "name": "pda",
+          "name": "battle",
           "writable": true,
           "pda": {
             "seeds": [
@@ -720,10 +633,7 @@ export type GameCore = {
                   116,
                   116,
                   108,
-                  101,
-                  50,
-                  118,
-                  50
+                  101
                 ]
               },
               {
@@ -734,16 +644,13 @@ export type GameCore = {
           }
         },
         {
-          "name": "ownerProgram",
-          "address": "EYYaUKGcq2epXWsXk52P7dEXpDMZQpGdkSXVDypzDhYm"
-        },
-        {
-          "name": "delegationProgram",
-          "address": "DELeGGvXpWV2fqJUhqcF5ZSYMS4JTLjteaAMARRSaeSh"
+          "name": "magicProgram",
+          "address": "Magic11111111111111111111111111111111111111"
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "magicContext",
+          "writable": true,
+          "address": "MagicContext1111111111111111111111111111111"
         }
       ],
       "args": [
@@ -754,20 +661,20 @@ export type GameCore = {
       ]
     },
     {
-      "name": "deployTroop",
+      "name": "commitBattle2v2",
       "discriminator": [
-        158,
-        193,
-        36,
+        40,
+        157,
         201,
-        175,
-        192,
-        250,
-        236
+        205,
+        225,
+        183,
+        145,
+        146
       ],
       "accounts": [
         {
-          "name": "signer",
+          "name": "payer",
           "writable": true,
           "signer": true
         },
@@ -784,7 +691,10 @@ export type GameCore = {
                   116,
                   116,
                   108,
-                  101
+                  101,
+                  50,
+                  118,
+                  50
                 ]
               },
               {
@@ -795,310 +705,286 @@ export type GameCore = {
           }
         },
         {
-          "name": "playerProfile",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  112,
-                  108,
-                  97,
-                  121,
-                  101,
-                  114
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "player_profile.authority",
-                "account": "playerProfile"
-              }
-            ]
-          }
+          "name": "magicProgram",
+          "address": "Magic11111111111111111111111111111111111111"
         },
         {
-          "name": "sessionToken",
-          "optional": true
+          "name": "magicContext",
+          "writable": true,
+          "address": "MagicContext1111111111111111111111111111111"
         }
       ],
       "args": [
         {
           "name": "gameId",
           "type": "u64"
-        },
-        {
-          "name": "cardIdx",
-          "type": "u8"
-        },
-        {
-          "name": "x",
-          "type": "i32"
-        },
-        {
-          "name": "y",
-          "type": "i32"
         }
       ]
     },
     {
-      "name": "deployTroop2v2",
+      "name": "createClan",
       "discriminator": [
-        74,
-        45,
-        183,
-        45,
+        89,
+        254,
+        237,
         205,
-        30,
-        55,
-        69
+        249,
+        101,
+        142,
+        223
       ],
       "accounts": [
         {
-          "name": "signer",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "battle",
+          "name": "clan",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  97,
-                  116,
-                  116,
+                  99,
                   108,
-                  101,
-                  50,
-                  118,
-                  50
+                  97,
+                  110
                 ]
               },
               {
                 "kind": "arg",
-                "path": "gameId"
+                "path": "name"
               }
             ]
           }
         },
         {
-          "name": "playerProfile",
+          "name": "clanMember",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
+                  99,
                   108,
                   97,
-                  121,
+                  110,
+                  95,
+                  109,
+                  101,
+                  109,
+                  98,
                   101,
                   114
                 ]
               },
               {
                 "kind": "account",
-                "path": "player_profile.authority",
-                "account": "playerProfile"
+                "path": "clan"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
               }
             ]
           }
         },
         {
-          "name": "sessionToken",
-          "optional": true
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "gameId",
-          "type": "u64"
-        },
-        {
-          "name": "cardIdx",
-          "type": "u8"
-        },
-        {
-          "name": "x",
-          "type": "i32"
-        },
-        {
-          "name": "y",
-          "type": "i32"
+          "name": "name",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "donateCards",
+      "name": "createGame",
       "discriminator": [
-        89,
-        151,
+        124,
+        69,
         75,
-        56,
-        254,
-        87,
-        126,
-        178
+        66,
+        184,
+        220,
+        72,
+        206
       ],
       "accounts": [
         {
-          "name": "clan",
-          "writable": true
-        },
-        {
-          "name": "donorProfile",
+          "name": "gameCounter",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  108,
+                  103,
                   97,
-                  121,
+                  109,
+                  101,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
                   101,
                   114
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "authority"
               }
             ]
           }
         },
         {
-          "name": "donorMember",
+          "name": "battle",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
-                  108,
-                  97,
-                  110,
-                  95,
-                  109,
-                  101,
-                  109,
                   98,
-                  101,
-                  114
+                  97,
+                  116,
+                  116,
+                  108,
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "clan"
-              },
-              {
-                "kind": "account",
-                "path": "authority"
+                "path": "game_counter.next_game_id",
+                "account": "gameCounter"
               }
             ]
           }
         },
         {
-          "name": "requesterProfile",
-          "writable": true
-        },
-        {
-          "name": "request",
-          "writable": true,
+          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  114,
-                  101,
-                  113,
-                  117,
-                  101,
-                  115,
-                  116
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "clan"
-              },
-              {
-                "kind": "account",
-                "path": "requester_profile.authority",
-                "account": "playerProfile"
               }
             ]
           }
         },
         {
-          "name": "mint",
-          "writable": true
-        },
-        {
-          "name": "donorTokenAccount",
-          "writable": true
-        },
-        {
-          "name": "mintAuthority",
+          "name": "playerOneProfile",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  109,
-                  105,
-                  110,
-                  116,
-                  95,
+                  112,
+                  108,
                   97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
+                  121,
+                  101,
+                  114
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "playerOne"
               }
             ]
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "queue1v1"
         },
         {
-          "name": "authority",
+          "name": "queue2v2"
+        },
+        {
+          "name": "activeBattle",
+          "optional": true
+        },
+        {
+          "name": "playerOne",
           "writable": true,
           "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "levelRule",
+          "type": {
+            "defined": {
+              "name": "levelRule"
+            }
+          }
+        },
+        {
+          "name": "invited",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "friendly",
+          "type": "bool"
+        }
+      ]
     },
     {
-      "name": "endGame",
+      "name": "createGame2v2",
       "discriminator": [
-        224,
-        135,
-        245,
-        99,
-        67,
-        175,
-        121,
-        252
+        105,
+        238,
+        1,
+        45,
+        18,
+        107,
+        75,
+        99
       ],
       "accounts": [
         {
-          "name": "signer",
+          "name": "gameCounter",
           "writable": true,
-          "signer": true
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  101,
+                  114
+                ]
+              }
+            ]
+          }
         },
         {
           "name": "battle",
@@ -1113,18 +999,41 @@ export type GameCore = {
                   116,
                   116,
                   108,
-                  101
+                  101,
+                  50,
+                  118,
+                  50
                 ]
               },
               {
-                "kind": "arg",
-                "path": "gameId"
+                "kind": "account",
+                "path": "game_counter.next_game_id",
+                "account": "gameCounter"
               }
             ]
           }
         },
         {
-          "name": "playerProfile",
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerOneProfile",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1140,48 +1049,71 @@ export type GameCore = {
               },
               {
                 "kind": "account",
-                "path": "player_profile.authority",
-                "account": "playerProfile"
+                "path": "playerOne"
               }
             ]
           }
         },
         {
-          "name": "sessionToken",
-          "optional": true
-        }
-      ],
-      "args": [
+          "name": "queue1v1"
+        },
         {
-          "name": "gameId",
-          "type": "u64"
+          "name": "queue2v2"
         },
         {
-          "name": "winnerIdx",
-          "type": "u8"
+          "name": "activeBattle",
+          "optional": true
+        },
+        {
+          "name": "playerOne",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "levelRule",
+          "type": {
+            "defined": {
+              "name": "levelRule"
+            }
+          }
+        },
+        {
+          "name": "invited",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "friendly",
+          "type": "bool"
         }
       ]
     },
     {
-      "name": "endGame2v2",
+      "name": "delegateGame",
       "discriminator": [
-        106,
-        180,
-        103,
-        190,
-        170,
-        159,
-        76,
-        234
+        116,
+        183,
+        70,
+        107,
+        112,
+        223,
+        122,
+        210
       ],
       "accounts": [
         {
-          "name": "signer",
-          "writable": true,
+          "name": "payer",
           "signer": true
         },
         {
-          "name": "battle",
+          "name": "bufferPda",
           "writable": true,
           "pda": {
             "seeds": [
@@ -1189,455 +1121,567 @@ export type GameCore = {
                 "kind": "const",
                 "value": [
                   98,
-                  97,
-                  116,
-                  116,
+                  117,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pda"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                201,
+                60,
+                163,
+                178,
+                222,
+                211,
+                170,
+                45,
+                63,
+                127,
+                183,
+                27,
+                119,
+                174,
+                113,
+                4,
+                116,
+                150,
+                226,
+                136,
+                32,
+                37,
+                213,
+                121,
+                165,
+                196,
+                103,
+                129,
+                155,
+                52,
+                15,
+                162
+              ]
+            }
+          }
+        },
+        {
+          "name": "delegationRecordPda",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
                   108,
                   101,
-                  50,
-                  118,
-                  50
+                  103,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
                 ]
               },
               {
-                "kind": "arg",
-                "path": "gameId"
+                "kind": "account",
+                "path": "pda"
               }
-            ]
+            ],
+            "program": {
+              "kind": "account",
+              "path": "delegationProgram"
+            }
           }
         },
         {
-          "name": "playerProfile",
+          "name": "delegationMetadataPda",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
+                  100,
+                  101,
                   108,
+                  101,
+                  103,
                   97,
-                  121,
+                  116,
+                  105,
+                  111,
+                  110,
+                  45,
+                  109,
                   101,
-                  114
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
                 ]
               },
               {
                 "kind": "account",
-                "path": "player_profile.authority",
-                "account": "playerProfile"
+                "path": "pda"
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "delegationProgram"
+            }
+          }
+        },
+        {
+          "name": "pda",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  116,
+                  116,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
               }
             ]
           }
         },
         {
-          "name": "sessionToken",
-          "optional": true
+          "name": "ownerProgram",
+          "address": "EYYaUKGcq2epXWsXk52P7dEXpDMZQpGdkSXVDypzDhYm"
+        },
+        {
+          "name": "delegationProgram",
+          "address": "DELeGGvXpWV2fqJUhqcF5ZSYMS4JTLjteaAMARRSaeSh"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
           "name": "gameId",
           "type": "u64"
-        },
-        {
-          "name": "winnerIdx",
-          "type": "u8"
         }
       ]
     },
     {
-      "name": "exportNft",
+      "name": "delegateGame2v2",
       "discriminator": [
-        158,
-        245,
-        88,
-        10,
-        98,
-        141,
-        173,
-        54
+        222,
+        184,
+        166,
+        241,
+        162,
+        5,
+        254,
+        156
       ],
       "accounts": [
         {
-          "name": "profile",
+          "name": "payer",
+          "signer": true
+        },
+        {
+          "name": "bufferPda",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  108,
-                  97,
-                  121,
+                  98,
+                  117,
+                  102,
+                  102,
                   101,
                   114
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "pda"
               }
-            ]
-          }
-        },
-        {
-          "name": "mint",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "destination",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "authority"
-              },
-              {
-                "kind": "const",
-                "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                201,
+                60,
+                163,
+                178,
+                222,
+                211,
+                170,
+                45,
+                63,
+                127,
+                183,
+                27,
+                119,
+                174,
+                113,
+                4,
+                116,
+                150,
+                226,
+                136,
+                32,
+                37,
+                213,
+                121,
+                165,
+                196,
+                103,
+                129,
+                155,
+                52,
+                15,
+                162
+              ]
+            }
+          }
+        },
+        {
+          "name": "delegationRecordPda",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
                   101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
-                  95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
                 ]
               },
               {
                 "kind": "account",
-                "path": "mint"
+                "path": "pda"
               }
             ],
             "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
+              "kind": "account",
+              "path": "delegationProgram"
             }
           }
         },
         {
-          "name": "cardMintState",
+          "name": "delegationMetadataPda",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
-                  97,
-                  114,
                   100,
-                  95,
-                  109,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
                   105,
+                  111,
                   110,
-                  116
+                  45,
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
                 ]
               },
               {
                 "kind": "account",
-                "path": "mint"
+                "path": "pda"
               }
-            ]
+            ],
+            "program": {
+              "kind": "account",
+              "path": "delegationProgram"
+            }
           }
         },
         {
-          "name": "metadata",
-          "writable": true
-        },
-        {
-          "name": "nftAuthority",
+          "name": "pda",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  110,
-                  102,
-                  116,
-                  95,
+                  98,
                   97,
-                  117,
                   116,
-                  104,
-                  111,
-                  114,
-                  105,
                   116,
-                  121
+                  108,
+                  101,
+                  50,
+                  118,
+                  50
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
               }
             ]
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+          "name": "ownerProgram",
+          "address": "EYYaUKGcq2epXWsXk52P7dEXpDMZQpGdkSXVDypzDhYm"
         },
         {
-          "name": "metadataProgram"
+          "name": "delegationProgram",
+          "address": "DELeGGvXpWV2fqJUhqcF5ZSYMS4JTLjteaAMARRSaeSh"
         },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
-        },
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
         }
       ],
       "args": [
         {
-          "name": "cardId",
-          "type": "u8"
+          "name": "gameId",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "exportResource",
+      "name": "deployTroop",
       "discriminator": [
-        141,
-        59,
-        69,
-        157,
-        212,
-        223,
-        39,
-        138
+        158,
+        193,
+        36,
+        201,
+        175,
+        192,
+        250,
+        236
       ],
       "accounts": [
         {
-          "name": "profile",
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "battle",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  108,
+                  98,
                   97,
-                  121,
-                  101,
-                  114
+                  116,
+                  116,
+                  108,
+                  101
                 ]
               },
               {
-                "kind": "account",
-                "path": "authority"
+                "kind": "arg",
+                "path": "gameId"
               }
             ]
           }
         },
         {
-          "name": "resourceMint",
-          "writable": true
-        },
-        {
-          "name": "destination",
-          "writable": true
+          "name": "player",
+          "docs": [
+            "wallet or holds a session token for it."
+          ]
         },
         {
-          "name": "resourceAuthority",
+          "name": "catalog",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  114,
-                  101,
-                  115,
-                  111,
-                  117,
-                  114,
                   99,
-                  101,
-                  95,
                   97,
-                  117,
                   116,
-                  104,
+                  97,
+                  108,
                   111,
-                  114,
-                  105,
-                  116,
-                  121
+                  103
                 ]
               }
             ]
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
+          "name": "sessionToken",
+          "optional": true
         }
       ],
       "args": [
         {
-          "name": "cardId",
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "cardIdx",
           "type": "u8"
         },
         {
-          "name": "amount",
-          "type": "u32"
+          "name": "x",
+          "type": "i32"
+        },
+        {
+          "name": "y",
+          "type": "i32"
         }
       ]
     },
     {
-      "name": "importResource",
+      "name": "deployTroop2v2",
       "discriminator": [
-        215,
-        192,
-        80,
-        211,
-        38,
-        83,
-        233,
-        152
+        74,
+        45,
+        183,
+        45,
+        205,
+        30,
+        55,
+        69
       ],
       "accounts": [
         {
-          "name": "profile",
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "battle",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  108,
+                  98,
                   97,
-                  121,
+                  116,
+                  116,
+                  108,
                   101,
-                  114
+                  50,
+                  118,
+                  50
                 ]
               },
               {
-                "kind": "account",
-                "path": "authority"
+                "kind": "arg",
+                "path": "gameId"
               }
             ]
           }
         },
         {
-          "name": "resourceMint",
-          "writable": true
-        },
-        {
-          "name": "source",
-          "writable": true
+          "name": "player",
+          "docs": [
+            "wallet or holds a session token for it."
+          ]
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "catalog",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true
+          "name": "sessionToken",
+          "optional": true
         }
       ],
       "args": [
         {
-          "name": "cardId",
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "cardIdx",
           "type": "u8"
         },
         {
-          "name": "amount",
-          "type": "u32"
+          "name": "x",
+          "type": "i32"
+        },
+        {
+          "name": "y",
+          "type": "i32"
         }
       ]
     },
     {
-      "name": "initializePlayer",
+      "name": "donateCards",
       "discriminator": [
-        79,
-        249,
-        88,
-        177,
-        220,
-        62,
+        89,
+        151,
+        75,
         56,
-        128
+        254,
+        87,
+        126,
+        178
       ],
       "accounts": [
         {
-          "name": "profile",
+          "name": "clan",
+          "writable": true
+        },
+        {
+          "name": "donorProfile",
           "writable": true,
           "pda": {
             "seeds": [
@@ -1660,42 +1704,7 @@ export type GameCore = {
           }
         },
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "username",
-          "type": "string"
-        }
-      ]
-    },
-    {
-      "name": "joinClan",
-      "discriminator": [
-        218,
-        113,
-        190,
-        239,
-        4,
-        91,
-        106,
-        206
-      ],
-      "accounts": [
-        {
-          "name": "clan",
-          "writable": true
-        },
-        {
-          "name": "clanMember",
-          "writable": true,
+          "name": "donorMember",
           "pda": {
             "seeds": [
               {
@@ -1726,101 +1735,102 @@ export type GameCore = {
           }
         },
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true
+          "name": "requesterProfile",
+          "writable": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "joinGame",
-      "discriminator": [
-        107,
-        112,
-        18,
-        38,
-        56,
-        173,
-        60,
-        128
-      ],
-      "accounts": [
-        {
-          "name": "battle",
+          "name": "request",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  97,
-                  116,
-                  116,
-                  108,
-                  101
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
                 ]
               },
               {
-                "kind": "arg",
-                "path": "gameId"
+                "kind": "account",
+                "path": "clan"
+              },
+              {
+                "kind": "account",
+                "path": "requester_profile.authority",
+                "account": "playerProfile"
               }
             ]
           }
         },
         {
-          "name": "playerTwoProfile",
+          "name": "mint",
+          "writable": true
+        },
+        {
+          "name": "donorTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "mintAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  108,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
                   97,
-                  121,
-                  101,
-                  114
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "playerTwo"
-              }
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
             ]
           }
         },
         {
-          "name": "playerTwo",
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "authority",
           "writable": true,
           "signer": true
         }
       ],
-      "args": [
-        {
-          "name": "gameId",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "joinGame2v2",
+      "name": "endGame",
       "discriminator": [
-        153,
-        119,
-        186,
-        81,
-        98,
-        163,
-        112,
-        151
+        224,
+        135,
+        245,
+        99,
+        67,
+        175,
+        121,
+        252
       ],
       "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
         {
           "name": "battle",
           "writable": true,
@@ -1834,10 +1844,7 @@ export type GameCore = {
                   116,
                   116,
                   108,
-                  101,
-                  50,
-                  118,
-                  50
+                  101
                 ]
               },
               {
@@ -1864,37 +1871,46 @@ export type GameCore = {
               },
               {
                 "kind": "account",
-                "path": "player"
+                "path": "player_profile.authority",
+                "account": "playerProfile"
               }
             ]
           }
         },
         {
-          "name": "player",
-          "writable": true,
-          "signer": true
+          "name": "sessionToken",
+          "optional": true
         }
       ],
       "args": [
         {
           "name": "gameId",
           "type": "u64"
+        },
+        {
+          "name": "winnerIdx",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "mintTrophies",
+      "name": "endGame2v2",
       "discriminator": [
-        43,
-        167,
-        115,
-        22,
-        194,
-        59,
-        61,
-        99
+        106,
+        180,
+        103,
+        190,
+        170,
+        159,
+        76,
+        234
       ],
       "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
         {
           "name": "battle",
           "writable": true,
@@ -1908,7 +1924,10 @@ export type GameCore = {
                   116,
                   116,
                   108,
-                  101
+                  101,
+                  50,
+                  118,
+                  50
                 ]
               },
               {
@@ -1919,8 +1938,7 @@ export type GameCore = {
           }
         },
         {
-          "name": "profile",
-          "writable": true,
+          "name": "playerProfile",
           "pda": {
             "seeds": [
               {
@@ -1936,101 +1954,95 @@ export type GameCore = {
               },
               {
                 "kind": "account",
-                "path": "signer"
-              }
-            ]
-          }
-        },
-        {
-          "name": "mint",
-          "writable": true
-        },
-        {
-          "name": "destination",
-          "writable": true
-        },
-        {
-          "name": "mintAuthority",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  109,
-                  105,
-                  110,
-                  116,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
+                "path": "player_profile.authority",
+                "account": "playerProfile"
               }
             ]
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "signer",
-          "writable": true,
-          "signer": true
+          "name": "sessionToken",
+          "optional": true
         }
       ],
       "args": [
         {
           "name": "gameId",
           "type": "u64"
+        },
+        {
+          "name": "winnerIdx",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "mintTrophies2v2",
+      "name": "enqueue",
       "discriminator": [
-        220,
-        127,
-        161,
-        50,
-        22,
-        83,
-        68,
-        65
+        151,
+        163,
+        241,
+        121,
+        38,
+        25,
+        78,
+        144
       ],
       "accounts": [
         {
-          "name": "battle",
+          "name": "queue",
+          "writable": true
+        },
+        {
+          "name": "otherQueue"
+        },
+        {
+          "name": "profile",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  97,
-                  116,
-                  116,
+                  112,
                   108,
+                  97,
+                  121,
                   101,
-                  50,
-                  118,
-                  50
+                  114
                 ]
               },
               {
-                "kind": "arg",
-                "path": "gameId"
+                "kind": "account",
+                "path": "player"
               }
             ]
           }
         },
+        {
+          "name": "activeBattle",
+          "optional": true
+        },
+        {
+          "name": "player",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "exportNft",
+      "discriminator": [
+        158,
+        245,
+        88,
+        10,
+        98,
+        141,
+        173,
+        54
+      ],
+      "accounts": [
         {
           "name": "profile",
           "writable": true,
@@ -2049,140 +2061,222 @@ export type GameCore = {
               },
               {
                 "kind": "account",
-                "path": "signer"
+                "path": "authority"
               }
             ]
           }
         },
         {
           "name": "mint",
-          "writable": true
+          "writable": true,
+          "signer": true
         },
         {
           "name": "destination",
-          "writable": true
-        },
-        {
-          "name": "mintAuthority",
+          "writable": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "account",
+                "path": "authority"
+              },
               {
                 "kind": "const",
                 "value": [
-                  109,
-                  105,
-                  110,
-                  116,
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
                   95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
                 ]
-              }
-            ]
-          }
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "cardMintState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  114,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "metadata",
+          "writable": true
+        },
+        {
+          "name": "nftAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  110,
+                  102,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
         },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
         {
-          "name": "signer",
-          "writable": true,
-          "signer": true
-        }
-      ],
-      "args": [
-        {
-          "name": "gameId",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "processUndelegation",
-      "discriminator": [
-        196,
-        28,
-        41,
-        206,
-        48,
-        37,
-        51,
-        167
-      ],
-      "accounts": [
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
         {
-          "name": "baseAccount",
-          "writable": true
+          "name": "metadataProgram"
         },
         {
-          "name": "buffer"
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
-          "name": "payer",
-          "writable": true
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
         },
         {
-          "name": "systemProgram"
+          "name": "authority",
+          "writable": true,
+          "signer": true
         }
       ],
       "args": [
         {
-          "name": "accountSeeds",
-          "type": {
-            "vec": "bytes"
-          }
+          "name": "cardId",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "requestCards",
+      "name": "exportResource",
       "discriminator": [
-        7,
-        238,
-        8,
+        141,
+        59,
+        69,
+        157,
+        212,
+        223,
         39,
-        188,
-        110,
-        76,
-        241
+        138
       ],
       "accounts": [
         {
-          "name": "clan"
-        },
-        {
-          "name": "clanMember",
+          "name": "profile",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
+                  112,
                   108,
                   97,
-                  110,
-                  95,
-                  109,
-                  101,
-                  109,
-                  98,
+                  121,
                   101,
                   114
                 ]
               },
-              {
-                "kind": "account",
-                "path": "clan"
-              },
               {
                 "kind": "account",
                 "path": "authority"
@@ -2191,8 +2285,15 @@ export type GameCore = {
           }
         },
         {
-          "name": "request",
-          "writable": true,
+          "name": "resourceMint",
+          "writable": true
+        },
+        {
+          "name": "destination",
+          "writable": true
+        },
+        {
+          "name": "resourceAuthority",
           "pda": {
             "seeds": [
               {
@@ -2200,52 +2301,59 @@ export type GameCore = {
                 "value": [
                   114,
                   101,
-                  113,
+                  115,
+                  111,
                   117,
+                  114,
+                  99,
                   101,
-                  115,
-                  116
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "clan"
-              },
-              {
-                "kind": "account",
-                "path": "authority"
               }
             ]
           }
         },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
         {
           "name": "authority",
           "writable": true,
           "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
           "name": "cardId",
           "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u32"
         }
       ]
     },
     {
-      "name": "setDeck",
+      "name": "importResource",
       "discriminator": [
-        27,
-        187,
-        7,
-        138,
-        227,
-        128,
-        88,
-        180
+        215,
+        192,
+        80,
+        211,
+        38,
+        83,
+        233,
+        152
       ],
       "accounts": [
         {
@@ -2272,11 +2380,11 @@ export type GameCore = {
           }
         },
         {
-          "name": "mint",
+          "name": "resourceMint",
           "writable": true
         },
         {
-          "name": "userTokenAccount",
+          "name": "source",
           "writable": true
         },
         {
@@ -2285,92 +2393,273 @@ export type GameCore = {
         },
         {
           "name": "authority",
+          "writable": true,
           "signer": true
         }
       ],
       "args": [
         {
-          "name": "newDeck",
-          "type": {
-            "array": [
-              "u8",
-              8
-            ]
-          }
+          "name": "cardId",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u32"
         }
       ]
     },
     {
-      "name": "unlockCard",
+      "name": "initializeCatalog",
       "discriminator": [
-        50,
-        235,
-        118,
-        114,
-        142,
-        155,
-        83,
-        176
+        30,
+        195,
+        79,
+        55,
+        197,
+        11,
+        102,
+        111
       ],
       "accounts": [
         {
-          "name": "profile",
+          "name": "catalog",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
+                  99,
+                  97,
+                  116,
+                  97,
                   108,
+                  111,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeConfig",
+      "discriminator": [
+        208,
+        127,
+        21,
+        1,
+        194,
+        190,
+        196,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeGameCounter",
+      "discriminator": [
+        75,
+        1,
+        124,
+        0,
+        175,
+        79,
+        133,
+        41
+      ],
+      "accounts": [
+        {
+          "name": "gameCounter",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
                   97,
-                  121,
+                  109,
+                  101,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
                   101,
                   114
                 ]
-              },
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
               {
-                "kind": "account",
-                "path": "authority"
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
               }
             ]
           }
         },
         {
-          "name": "mint",
-          "writable": true
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
         },
         {
-          "name": "userTokenAccount",
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "firstGameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeMatchQueue",
+      "discriminator": [
+        164,
+        152,
+        59,
+        142,
+        60,
+        77,
+        221,
+        77
+      ],
+      "accounts": [
+        {
+          "name": "queue",
           "writable": true
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "authority",
-          "signer": true
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "cardId",
+          "name": "teamSize",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "upgradeCard",
+      "name": "initializePlayer",
       "discriminator": [
-        192,
-        16,
-        120,
+        79,
+        249,
+        88,
+        177,
         220,
-        55,
-        173,
-        65,
-        234
+        62,
+        56,
+        128
       ],
       "accounts": [
         {
@@ -2396,570 +2685,3559 @@ export type GameCore = {
             ]
           }
         },
-        {
-          "name": "mint",
-          "writable": true
-        },
-        {
-          "name": "userTokenAccount",
-          "writable": true
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
         {
           "name": "authority",
+          "writable": true,
           "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "cardId",
-          "type": "u8"
+          "name": "username",
+          "type": "string"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "battleState",
-      "discriminator": [
-        106,
-        85,
-        43,
-        49,
-        97,
-        18,
-        43,
-        245
-      ]
     },
     {
-      "name": "battleState2v2",
+      "name": "initializeSeason",
       "discriminator": [
-        79,
-        9,
-        64,
-        38,
-        124,
-        220,
-        1,
-        103
-      ]
-    },
-    {
-      "name": "cardMintState",
-      "discriminator": [
-        251,
-        17,
-        76,
-        224,
-        90,
-        173,
-        223,
-        58
-      ]
-    },
-    {
-      "name": "clan",
-      "discriminator": [
-        179,
-        33,
-        233,
-        29,
-        6,
-        237,
-        105,
-        241
-      ]
+        48,
+        218,
+        111,
+        51,
+        235,
+        207,
+        4,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "season",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  97,
+                  115,
+                  111,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "clanMember",
+      "name": "joinClan",
       "discriminator": [
-        40,
-        179,
-        94,
-        64,
-        102,
-        181,
-        208,
-        153
-      ]
+        218,
+        113,
+        190,
+        239,
+        4,
+        91,
+        106,
+        206
+      ],
+      "accounts": [
+        {
+          "name": "clan",
+          "writable": true
+        },
+        {
+          "name": "clanMember",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  108,
+                  97,
+                  110,
+                  95,
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "clan"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "profile",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "donationRequest",
+      "name": "joinGame",
       "discriminator": [
-        93,
-        127,
+        107,
+        112,
+        18,
         38,
-        133,
-        130,
-        151,
-        28,
-        63
+        56,
+        173,
+        60,
+        128
+      ],
+      "accounts": [
+        {
+          "name": "battle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  116,
+                  116,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerTwoProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "playerTwo"
+              }
+            ]
+          }
+        },
+        {
+          "name": "queue1v1"
+        },
+        {
+          "name": "queue2v2"
+        },
+        {
+          "name": "activeBattle",
+          "optional": true
+        },
+        {
+          "name": "playerTwo",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
       ]
     },
     {
-      "name": "playerProfile",
+      "name": "joinGame2v2",
       "discriminator": [
-        82,
-        226,
-        99,
-        87,
-        164,
-        130,
-        181,
-        80
+        153,
+        119,
+        186,
+        81,
+        98,
+        163,
+        112,
+        151
+      ],
+      "accounts": [
+        {
+          "name": "battle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  116,
+                  116,
+                  108,
+                  101,
+                  50,
+                  118,
+                  50
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "queue1v1"
+        },
+        {
+          "name": "queue2v2"
+        },
+        {
+          "name": "activeBattle",
+          "optional": true
+        },
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
       ]
     },
     {
-      "name": "sessionToken",
+      "name": "leaveQueue",
       "discriminator": [
-        233,
-        4,
-        115,
-        14,
-        46,
-        21,
-        1,
-        15
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "notEnoughTokens",
-      "msg": "Not enough currency"
-    },
-    {
-      "code": 6001,
-      "name": "cardAlreadyUnlocked",
-      "msg": "Card already unlocked"
-    },
-    {
-      "code": 6002,
-      "name": "inventoryFull",
-      "msg": "Inventory full"
-    },
-    {
-      "code": 6003,
-      "name": "cardNotOwned",
-      "msg": "Card not owned"
-    },
-    {
-      "code": 6004,
-      "name": "invalidCardIdx",
-      "msg": "Invalid card index"
-    },
-    {
-      "code": 6005,
-      "name": "emptyCardSlot",
-      "msg": "Empty card slot"
-    },
-    {
-      "code": 6006,
-      "name": "invalidCardId",
-      "msg": "Invalid card ID"
-    },
-    {
-      "code": 6007,
-      "name": "notEnoughElixir",
-      "msg": "Not enough elixir"
-    },
-    {
-      "code": 6008,
-      "name": "tooManyEntities",
-      "msg": "Too many entities"
-    },
-    {
-      "code": 6009,
-      "name": "gameNotFinished",
-      "msg": "Game not finished"
-    },
-    {
-      "code": 6010,
-      "name": "notWinner",
-      "msg": "Not winner"
-    },
-    {
-      "code": 6011,
-      "name": "alreadyClaimed",
-      "msg": "Already claimed"
-    },
-    {
-      "code": 6012,
-      "name": "invalidAuth",
-      "msg": "Invalid auth"
-    },
-    {
-      "code": 6013,
-      "name": "maxLevelReached",
-      "msg": "Max level reached"
-    },
-    {
-      "code": 6014,
-      "name": "notEnoughCards",
-      "msg": "Not enough cards"
-    },
-    {
-      "code": 6015,
-      "name": "clanFull",
-      "msg": "Clan full"
-    },
-    {
-      "code": 6016,
-      "name": "alreadyInClan",
-      "msg": "Already in clan"
+        95,
+        75,
+        87,
+        92,
+        172,
+        245,
+        65,
+        97
+      ],
+      "accounts": [
+        {
+          "name": "queue",
+          "writable": true
+        },
+        {
+          "name": "profile",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "signer": true
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6017,
-      "name": "clanNameTooLong",
-      "msg": "Clan name too long"
+      "name": "migrateProfile",
+      "discriminator": [
+        224,
+        187,
+        132,
+        189,
+        185,
+        163,
+        183,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6018,
-      "name": "requestCooldown",
-      "msg": "Request cooldown active"
-    },
-    {
-      "code": 6019,
-      "name": "requestNotActive",
-      "msg": "Request not active"
-    },
-    {
-      "code": 6020,
-      "name": "requestFull",
-      "msg": "Request full"
-    },
-    {
-      "code": 6021,
-      "name": "cannotDonateToSelf",
-      "msg": "Cannot donate to self"
-    },
-    {
-      "code": 6022,
-      "name": "invalidPlayer",
-      "msg": "Invalid player"
+      "name": "pairPlayers",
+      "discriminator": [
+        119,
+        56,
+        160,
+        44,
+        189,
+        197,
+        250,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "queue",
+          "writable": true
+        },
+        {
+          "name": "gameCounter",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  101,
+                  114
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "battle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  116,
+                  116,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game_counter.next_game_id",
+                "account": "gameCounter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6023,
-      "name": "unauthorized",
-      "msg": "unauthorized"
+      "name": "pairPlayers2v2",
+      "discriminator": [
+        121,
+        165,
+        170,
+        216,
+        96,
+        255,
+        112,
+        224
+      ],
+      "accounts": [
+        {
+          "name": "queue",
+          "writable": true
+        },
+        {
+          "name": "gameCounter",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  101,
+                  114
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "battle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  116,
+                  116,
+                  108,
+                  101,
+                  50,
+                  118,
+                  50
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game_counter.next_game_id",
+                "account": "gameCounter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6024,
-      "name": "gameAlreadyFull",
-      "msg": "Game is already full"
+      "name": "processUndelegation",
+      "discriminator": [
+        196,
+        28,
+        41,
+        206,
+        48,
+        37,
+        51,
+        167
+      ],
+      "accounts": [
+        {
+          "name": "baseAccount",
+          "writable": true
+        },
+        {
+          "name": "buffer"
+        },
+        {
+          "name": "payer",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "accountSeeds",
+          "type": {
+            "vec": "bytes"
+          }
+        }
+      ]
     },
     {
-      "code": 6025,
-      "name": "gameNotActive",
-      "msg": "Game is not active"
+      "name": "removeCard",
+      "discriminator": [
+        48,
+        3,
+        98,
+        167,
+        165,
+        223,
+        51,
+        100
+      ],
+      "accounts": [
+        {
+          "name": "catalog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "cardId",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "requestCards",
+      "discriminator": [
+        7,
+        238,
+        8,
+        39,
+        188,
+        110,
+        76,
+        241
+      ],
+      "accounts": [
+        {
+          "name": "clan"
+        },
+        {
+          "name": "clanMember",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  108,
+                  97,
+                  110,
+                  95,
+                  109,
+                  101,
+                  109,
+                  98,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "clan"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "request",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "clan"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "cardId",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "rollPlayerSeason",
+      "discriminator": [
+        217,
+        90,
+        216,
+        45,
+        252,
+        106,
+        223,
+        173
+      ],
+      "accounts": [
+        {
+          "name": "season",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  97,
+                  115,
+                  111,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "profile.authority",
+                "account": "playerProfile"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rollSeason",
+      "discriminator": [
+        13,
+        133,
+        214,
+        162,
+        232,
+        66,
+        39,
+        163
+      ],
+      "accounts": [
+        {
+          "name": "season",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  97,
+                  115,
+                  111,
+                  110
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setCardStats",
+      "discriminator": [
+        236,
+        62,
+        164,
+        228,
+        145,
+        253,
+        177,
+        81
+      ],
+      "accounts": [
+        {
+          "name": "catalog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  97,
+                  108,
+                  111,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "stats",
+          "type": {
+            "defined": {
+              "name": "cardStats"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setDeck",
+      "discriminator": [
+        27,
+        187,
+        7,
+        138,
+        227,
+        128,
+        88,
+        180
+      ],
+      "accounts": [
+        {
+          "name": "profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true
+        },
+        {
+          "name": "userTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newDeck",
+          "type": {
+            "array": [
+              "u8",
+              8
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "settleBattle",
+      "discriminator": [
+        4,
+        146,
+        32,
+        157,
+        82,
+        216,
+        214,
+        28
+      ],
+      "accounts": [
+        {
+          "name": "battle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  116,
+                  116,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "season",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  97,
+                  115,
+                  111,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerOneProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "battle.players [0]",
+                "account": "battleState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerTwoProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "battle.players [1]",
+                "account": "battleState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerOneTokens",
+          "writable": true
+        },
+        {
+          "name": "playerTwoTokens",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "writable": true
+        },
+        {
+          "name": "mintAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "caller",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "settleBattle2v2",
+      "discriminator": [
+        161,
+        2,
+        241,
+        188,
+        184,
+        189,
+        213,
+        225
+      ],
+      "accounts": [
+        {
+          "name": "battle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  116,
+                  116,
+                  108,
+                  101,
+                  50,
+                  118,
+                  50
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "season",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  97,
+                  115,
+                  111,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerOneProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "battle.players [0]",
+                "account": "battleState2v2"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerTwoProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "battle.players [1]",
+                "account": "battleState2v2"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerThreeProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "battle.players [2]",
+                "account": "battleState2v2"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerFourProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "battle.players [3]",
+                "account": "battleState2v2"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerOneTokens",
+          "writable": true
+        },
+        {
+          "name": "playerTwoTokens",
+          "writable": true
+        },
+        {
+          "name": "playerThreeTokens",
+          "writable": true
+        },
+        {
+          "name": "playerFourTokens",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "writable": true
+        },
+        {
+          "name": "mintAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "caller",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "surrender",
+      "discriminator": [
+        156,
+        0,
+        23,
+        44,
+        81,
+        56,
+        119,
+        164
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "battle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  116,
+                  116,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "docs": [
+            "wallet or holds a session token for it."
+          ]
+        },
+        {
+          "name": "sessionToken",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "surrender2v2",
+      "discriminator": [
+        240,
+        201,
+        155,
+        103,
+        121,
+        19,
+        231,
+        12
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "battle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  116,
+                  116,
+                  108,
+                  101,
+                  50,
+                  118,
+                  50
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "docs": [
+            "wallet or holds a session token for it."
+          ]
+        },
+        {
+          "name": "sessionToken",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "unlockCard",
+      "discriminator": [
+        50,
+        235,
+        118,
+        114,
+        142,
+        155,
+        83,
+        176
+      ],
+      "accounts": [
+        {
+          "name": "profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true
+        },
+        {
+          "name": "userTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "cardId",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "updateAfkTimeout",
+      "discriminator": [
+        65,
+        49,
+        132,
+        237,
+        209,
+        30,
+        239,
+        217
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "afkTimeoutSeconds",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "updateArena",
+      "discriminator": [
+        179,
+        14,
+        84,
+        80,
+        59,
+        23,
+        182,
+        226
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "arena",
+          "type": {
+            "defined": {
+              "name": "arenaMap"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateElixirSchedule",
+      "discriminator": [
+        60,
+        98,
+        219,
+        221,
+        123,
+        73,
+        222,
+        77
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "schedule",
+          "type": {
+            "defined": {
+              "name": "elixirSchedule"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateMatchClock",
+      "discriminator": [
+        255,
+        105,
+        231,
+        243,
+        102,
+        72,
+        202,
+        123
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "regulationSeconds",
+          "type": "u32"
+        },
+        {
+          "name": "overtimeSeconds",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "upgradeCard",
+      "discriminator": [
+        192,
+        16,
+        120,
+        220,
+        55,
+        173,
+        65,
+        234
+      ],
+      "accounts": [
+        {
+          "name": "profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true
+        },
+        {
+          "name": "userTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "cardId",
+          "type": "u8"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "battleState",
+      "discriminator": [
+        106,
+        85,
+        43,
+        49,
+        97,
+        18,
+        43,
+        245
+      ]
+    },
+    {
+      "name": "battleState2v2",
+      "discriminator": [
+        79,
+        9,
+        64,
+        38,
+        124,
+        220,
+        1,
+        103
+      ]
+    },
+    {
+      "name": "cardCatalog",
+      "discriminator": [
+        228,
+        10,
+        147,
+        7,
+        253,
+        1,
+        136,
+        233
+      ]
+    },
+    {
+      "name": "cardMintState",
+      "discriminator": [
+        251,
+        17,
+        76,
+        224,
+        90,
+        173,
+        223,
+        58
+      ]
+    },
+    {
+      "name": "clan",
+      "discriminator": [
+        179,
+        33,
+        233,
+        29,
+        6,
+        237,
+        105,
+        241
+      ]
+    },
+    {
+      "name": "clanMember",
+      "discriminator": [
+        40,
+        179,
+        94,
+        64,
+        102,
+        181,
+        208,
+        153
+      ]
+    },
+    {
+      "name": "donationRequest",
+      "discriminator": [
+        93,
+        127,
+        38,
+        133,
+        130,
+        151,
+        28,
+        63
+      ]
+    },
+    {
+      "name": "gameConfig",
+      "discriminator": [
+        45,
+        146,
+        146,
+        33,
+        170,
+        69,
+        96,
+        133
+      ]
+    },
+    {
+      "name": "gameCounter",
+      "discriminator": [
+        117,
+        67,
+        148,
+        185,
+        138,
+        194,
+        249,
+        87
+      ]
+    },
+    {
+      "name": "matchQueue",
+      "discriminator": [
+        186,
+        184,
+        159,
+        96,
+        38,
+        234,
+        164,
+        181
+      ]
+    },
+    {
+      "name": "playerProfile",
+      "discriminator": [
+        82,
+        226,
+        99,
+        87,
+        164,
+        130,
+        181,
+        80
+      ]
+    },
+    {
+      "name": "season",
+      "discriminator": [
+        76,
+        67,
+        93,
+        156,
+        180,
+        157,
+        248,
+        47
+      ]
+    },
+    {
+      "name": "sessionToken",
+      "discriminator": [
+        233,
+        4,
+        115,
+        14,
+        46,
+        21,
+        1,
+        15
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "notEnoughTokens",
+      "msg": "Not enough currency"
+    },
+    {
+      "code": 6001,
+      "name": "cardAlreadyUnlocked",
+      "msg": "Card already unlocked"
+    },
+    {
+      "code": 6002,
+      "name": "inventoryFull",
+      "msg": "Inventory full"
+    },
+    {
+      "code": 6003,
+      "name": "cardNotOwned",
+      "msg": "Card not owned"
+    },
+    {
+      "code": 6004,
+      "name": "invalidCardIdx",
+      "msg": "Invalid card index"
+    },
+    {
+      "code": 6005,
+      "name": "emptyCardSlot",
+      "msg": "Empty card slot"
+    },
+    {
+      "code": 6006,
+      "name": "invalidCardId",
+      "msg": "Invalid card ID"
+    },
+    {
+      "code": 6007,
+      "name": "notEnoughElixir",
+      "msg": "Not enough elixir"
+    },
+    {
+      "code": 6008,
+      "name": "tooManyEntities",
+      "msg": "Too many entities"
+    },
+    {
+      "code": 6009,
+      "name": "gameNotFinished",
+      "msg": "Game not finished"
+    },
+    {
+      "code": 6010,
+      "name": "notWinner",
+      "msg": "Not winner"
+    },
+    {
+      "code": 6011,
+      "name": "alreadyClaimed",
+      "msg": "Already claimed"
+    },
+    {
+      "code": 6012,
+      "name": "invalidAuth",
+      "msg": "Invalid auth"
+    },
+    {
+      "code": 6013,
+      "name": "maxLevelReached",
+      "msg": "Max level reached"
+    },
+    {
+      "code": 6014,
+      "name": "notEnoughCards",
+      "msg": "Not enough cards"
+    },
+    {
+      "code": 6015,
+      "name": "clanFull",
+      "msg": "Clan full"
+    },
+    {
+      "code": 6016,
+      "name": "alreadyInClan",
+      "msg": "Already in clan"
+    },
+    {
+      "code": 6017,
+      "name": "clanNameTooLong",
+      "msg": "Clan name too long"
+    },
+    {
+      "code": 6018,
+      "name": "requestCooldown",
+      "msg": "Request cooldown active"
+    },
+    {
+      "code": 6019,
+      "name": "requestNotActive",
+      "msg": "Request not active"
+    },
+    {
+      "code": 6020,
+      "name": "requestFull",
+      "msg": "Request full"
+    },
+    {
+      "code": 6021,
+      "name": "cannotDonateToSelf",
+      "msg": "Cannot donate to self"
+    },
+    {
+      "code": 6022,
+      "name": "invalidPlayer",
+      "msg": "Invalid player"
+    },
+    {
+      "code": 6023,
+      "name": "unauthorized",
+      "msg": "unauthorized"
+    },
+    {
+      "code": 6024,
+      "name": "gameAlreadyFull",
+      "msg": "Game is already full"
+    },
+    {
+      "code": 6025,
+      "name": "gameNotActive",
+      "msg": "Game is not active"
+    },
+    {
+      "code": 6026,
+      "name": "gameNotWaiting",
+      "msg": "Game is not in waiting state"
+    },
+    {
+      "code": 6027,
+      "name": "notAPlayer",
+      "msg": "You are not a player in this game"
+    },
+    {
+      "code": 6028,
+      "name": "winnerNotDetermined",
+      "msg": "Winner has not been determined yet"
+    },
+    {
+      "code": 6029,
+      "name": "alreadyMinted",
+      "msg": "Trophies already minted for this game"
+    },
+    {
+      "code": 6030,
+      "name": "resultMismatch",
+      "msg": "Claimed result does not match the battle state"
+    },
+    {
+      "code": 6031,
+      "name": "invalidConfig",
+      "msg": "Invalid config"
+    },
+    {
+      "code": 6032,
+      "name": "catalogFull",
+      "msg": "Card catalog is full"
+    },
+    {
+      "code": 6033,
+      "name": "invalidPlacement",
+      "msg": "Cannot deploy at that position"
+    },
+    {
+      "code": 6034,
+      "name": "cardNotInHand",
+      "msg": "Card is not in hand"
+    },
+    {
+      "code": 6035,
+      "name": "slotHashesUnavailable",
+      "msg": "Slot hashes unavailable"
+    },
+    {
+      "code": 6036,
+      "name": "handsNotDealt",
+      "msg": "Hands are dealt on the first tick after the seed slot"
+    },
+    {
+      "code": 6037,
+      "name": "invalidLevelRule",
+      "msg": "Invalid level rule"
+    },
+    {
+      "code": 6038,
+      "name": "lobbyNotExpired",
+      "msg": "Lobby has not timed out yet"
+    },
+    {
+      "code": 6039,
+      "name": "rewardsNotSettled",
+      "msg": "Rewards have not been settled yet"
+    },
+    {
+      "code": 6040,
+      "name": "playerLeft",
+      "msg": "Player has left this game"
+    },
+    {
+      "code": 6041,
+      "name": "timeoutNotReached",
+      "msg": "No side has timed out"
+    },
+    {
+      "code": 6042,
+      "name": "alreadySettled",
+      "msg": "Battle already settled"
+    },
+    {
+      "code": 6043,
+      "name": "arenaLocked",
+      "msg": "Card is not available in your arena yet"
+    },
+    {
+      "code": 6044,
+      "name": "invalidMilestone",
+      "msg": "Invalid trophy road milestone"
+    },
+    {
+      "code": 6045,
+      "name": "milestoneNotReached",
+      "msg": "Not enough trophies for this milestone"
+    },
+    {
+      "code": 6046,
+      "name": "rewardAlreadyClaimed",
+      "msg": "Reward already claimed"
+    },
+    {
+      "code": 6047,
+      "name": "notEnoughTrophies",
+      "msg": "Not enough trophies to join this clan"
+    },
+    {
+      "code": 6048,
+      "name": "seasonNotOver",
+      "msg": "Season has not ended yet"
+    },
+    {
+      "code": 6049,
+      "name": "noSeasonReward",
+      "msg": "No season reward to claim"
+    },
+    {
+      "code": 6050,
+      "name": "queueFull",
+      "msg": "Match queue is full"
+    },
+    {
+      "code": 6051,
+      "name": "alreadyQueued",
+      "msg": "Already in the match queue"
+    },
+    {
+      "code": 6052,
+      "name": "notQueued",
+      "msg": "Not in the match queue"
+    },
+    {
+      "code": 6053,
+      "name": "noMatchFound",
+      "msg": "No players can be matched yet"
+    },
+    {
+      "code": 6054,
+      "name": "invalidInviteList",
+      "msg": "Invite list must fill every open seat without the creator or repeats"
+    },
+    {
+      "code": 6055,
+      "name": "notInvited",
+      "msg": "Not invited to this lobby"
+    },
+    {
+      "code": 6056,
+      "name": "friendlyBattle",
+      "msg": "Friendly battles are not settled"
+    },
+    {
+      "code": 6057,
+      "name": "stillInBattle",
+      "msg": "The player's last battle is not over yet"
+    },
+    {
+      "code": 6058,
+      "name": "matchedProfileMismatch",
+      "msg": "Remaining accounts must be the matched players' profiles in match order"
+    },
+    {
+      "code": 6059,
+      "name": "profileUpToDate",
+      "msg": "Profile is already on the current layout"
+    }
+  ],
+  "types": [
+    {
+      "name": "arenaMap",
+      "docs": [
+        "Deployable grid and tower positions in tile coordinates. Clients render and validate",
+        "placement from the same numbers."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minX",
+            "type": "i32"
+          },
+          {
+            "name": "maxX",
+            "type": "i32"
+          },
+          {
+            "name": "minY",
+            "type": "i32"
+          },
+          {
+            "name": "maxY",
+            "type": "i32"
+          },
+          {
+            "name": "riverMinY",
+            "docs": [
+              "River rows (inclusive). Nothing can be deployed on them."
+            ],
+            "type": "i32"
+          },
+          {
+            "name": "riverMaxY",
+            "type": "i32"
+          },
+          {
+            "name": "pocketDepth",
+            "docs": [
+              "Rows past the river that open on a lane once the enemy princess tower there falls"
+            ],
+            "type": "i32"
+          },
+          {
+            "name": "towers",
+            "docs": [
+              "Tower centres in layout order: side 0's king, left and right princess, then side 1's"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "towerSite"
+                  }
+                },
+                6
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "battleState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameId",
+            "type": "u64"
+          },
+          {
+            "name": "players",
+            "type": {
+              "array": [
+                "pubkey",
+                2
+              ]
+            }
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Account that paid the battle's rent and gets it back when it is closed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "gameStatus"
+              }
+            }
+          },
+          {
+            "name": "tickCount",
+            "type": "u64"
+          },
+          {
+            "name": "elixir",
+            "type": {
+              "array": [
+                "u64",
+                2
+              ]
+            }
+          },
+          {
+            "name": "towers",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "tower"
+                  }
+                },
+                6
+              ]
+            }
+          },
+          {
+            "name": "entities",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "entity"
+                }
+              }
+            }
+          },
+          {
+            "name": "nextEntityId",
+            "docs": [
+              "Id handed to the next spawned entity; never reused"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "winner",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "settled",
+            "docs": [
+              "Whether ratings and rewards have been applied to every participant"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "towersDestroyed",
+            "docs": [
+              "How many of the enemy's towers each player has destroyed (princess only)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "damageDealt",
+            "docs": [
+              "Total HP damage dealt to enemy towers by each player"
+            ],
+            "type": {
+              "array": [
+                "u64",
+                2
+              ]
+            }
+          },
+          {
+            "name": "lastUpdateTime",
+            "type": "i64"
+          },
+          {
+            "name": "lastTickTime",
+            "docs": [
+              "Unix timestamp the simulation was last advanced to"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Unix timestamp the lobby was created"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "startedAt",
+            "docs": [
+              "Unix timestamp the game became Active"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "endedAt",
+            "docs": [
+              "Unix timestamp the game became Completed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "endReason",
+            "type": {
+              "defined": {
+                "name": "endReason"
+              }
+            }
+          },
+          {
+            "name": "lastActionAt",
+            "docs": [
+              "Unix timestamp of each player's last deploy, or of activation if none yet"
+            ],
+            "type": {
+              "array": [
+                "i64",
+                2
+              ]
+            }
+          },
+          {
+            "name": "regulationSeconds",
+            "type": "u32"
+          },
+          {
+            "name": "overtimeSeconds",
+            "type": "u32"
+          },
+          {
+            "name": "afkTimeoutSeconds",
+            "type": "u32"
+          },
+          {
+            "name": "elixirSchedule",
+            "type": {
+              "defined": {
+                "name": "elixirSchedule"
+              }
+            }
+          },
+          {
+            "name": "elixirCarry",
+            "docs": [
+              "Elixir-unit milliseconds accrued towards the next whole unit"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "arena",
+            "type": {
+              "defined": {
+                "name": "arenaMap"
+              }
+            }
+          },
+          {
+            "name": "levelRule",
+            "type": {
+              "defined": {
+                "name": "levelRule"
+              }
+            }
+          },
+          {
+            "name": "invited",
+            "docs": [
+              "Players allowed to join; empty means anyone may"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "friendly",
+            "docs": [
+              "Friendly battles are never settled: trophies, MMR and rewards stay as they were"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "decks",
+            "docs": [
+              "Each player's deck and card levels, fixed when they joined"
+            ],
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    {
+                      "defined": {
+                        "name": "deckCard"
+                      }
+                    },
+                    8
+                  ]
+                },
+                2
+              ]
+            }
+          },
+          {
+            "name": "cardQueues",
+            "docs": [
+              "Per-player queues of deck slots; the first HAND_SIZE are the hand"
+            ],
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u8",
+                    8
+                  ]
+                },
+                2
+              ]
+            }
+          },
+          {
+            "name": "shuffleSeed",
+            "docs": [
+              "Seed the queues were shuffled with: hash of `seed_slot` mixed with game_id"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "seedSlot",
+            "docs": [
+              "Slot whose hash seeds the shuffle: SEED_SLOT_DELAY after activation until the",
+              "hands are dealt, then the slot the hash actually came from"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "handsDealt",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "battleState2v2",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameId",
+            "type": "u64"
+          },
+          {
+            "name": "players",
+            "type": {
+              "array": [
+                "pubkey",
+                4
+              ]
+            }
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Account that paid the battle's rent and gets it back when it is closed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "gameStatus"
+              }
+            }
+          },
+          {
+            "name": "tickCount",
+            "type": "u64"
+          },
+          {
+            "name": "elixir",
+            "type": {
+              "array": [
+                "u64",
+                4
+              ]
+            }
+          },
+          {
+            "name": "towers",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "tower"
+                  }
+                },
+                6
+              ]
+            }
+          },
+          {
+            "name": "entities",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "entity"
+                }
+              }
+            }
+          },
+          {
+            "name": "nextEntityId",
+            "docs": [
+              "Id handed to the next spawned entity; never reused"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "winner",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "surrendered",
+            "docs": [
+              "Players who have surrendered and left; a team forfeits once both have"
+            ],
+            "type": {
+              "array": [
+                "bool",
+                4
+              ]
+            }
+          },
+          {
+            "name": "settled",
+            "docs": [
+              "Whether ratings and rewards have been applied to every participant"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "towersDestroyed",
+            "docs": [
+              "How many of the enemy's towers each team has destroyed (princess only)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "damageDealt",
+            "docs": [
+              "Total HP damage dealt to enemy towers by each team"
+            ],
+            "type": {
+              "array": [
+                "u64",
+                2
+              ]
+            }
+          },
+          {
+            "name": "lastUpdateTime",
+            "type": "i64"
+          },
+          {
+            "name": "lastTickTime",
+            "docs": [
+              "Unix timestamp the simulation was last advanced to"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Unix timestamp the lobby was created"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "startedAt",
+            "docs": [
+              "Unix timestamp the game became Active"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "endedAt",
+            "docs": [
+              "Unix timestamp the game became Completed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "endReason",
+            "type": {
+              "defined": {
+                "name": "endReason"
+              }
+            }
+          },
+          {
+            "name": "lastActionAt",
+            "docs": [
+              "Unix timestamp of each player's last deploy, or of activation if none yet"
+            ],
+            "type": {
+              "array": [
+                "i64",
+                4
+              ]
+            }
+          },
+          {
+            "name": "regulationSeconds",
+            "type": "u32"
+          },
+          {
+            "name": "overtimeSeconds",
+            "type": "u32"
+          },
+          {
+            "name": "afkTimeoutSeconds",
+            "type": "u32"
+          },
+          {
+            "name": "elixirSchedule",
+            "type": {
+              "defined": {
+                "name": "elixirSchedule"
+              }
+            }
+          },
+          {
+            "name": "elixirCarry",
+            "docs": [
+              "Elixir-unit milliseconds accrued towards the next whole unit"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "arena",
+            "type": {
+              "defined": {
+                "name": "arenaMap"
+              }
+            }
+          },
+          {
+            "name": "levelRule",
+            "type": {
+              "defined": {
+                "name": "levelRule"
+              }
+            }
+          },
+          {
+            "name": "invited",
+            "docs": [
+              "Players allowed to join; empty means anyone may"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "friendly",
+            "docs": [
+              "Friendly battles are never settled: trophies, MMR and rewards stay as they were"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "decks",
+            "docs": [
+              "Each player's deck and card levels, fixed when they joined"
+            ],
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    {
+                      "defined": {
+                        "name": "deckCard"
+                      }
+                    },
+                    8
+                  ]
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "cardQueues",
+            "docs": [
+              "Per-player queues of deck slots; the first HAND_SIZE are the hand"
+            ],
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u8",
+                    8
+                  ]
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "shuffleSeed",
+            "docs": [
+              "Seed the queues were shuffled with: hash of `seed_slot` mixed with game_id"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "seedSlot",
+            "docs": [
+              "Slot whose hash seeds the shuffle: SEED_SLOT_DELAY after activation until the",
+              "hands are dealt, then the slot the hash actually came from"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "handsDealt",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "cardCatalog",
+      "docs": [
+        "Admin-managed card stats, seeded by [b\"catalog\"]. Deploys read base stats from here."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cards",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "cardStats"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6026,
-      "name": "gameNotWaiting",
-      "msg": "Game is not in waiting state"
+      "name": "cardKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "troop"
+          },
+          {
+            "name": "spell"
+          },
+          {
+            "name": "building"
+          }
+        ]
+      }
     },
     {
-      "code": 6027,
-      "name": "notAPlayer",
-      "msg": "You are not a player in this game"
+      "name": "cardMintState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cardId",
+            "type": "u8"
+          },
+          {
+            "name": "level",
+            "type": "u8"
+          },
+          {
+            "name": "xp",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6028,
-      "name": "winnerNotDetermined",
-      "msg": "Winner has not been determined yet"
+      "name": "cardProgress",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cardId",
+            "type": "u8"
+          },
+          {
+            "name": "level",
+            "type": "u8"
+          },
+          {
+            "name": "xp",
+            "type": "u16"
+          },
+          {
+            "name": "amount",
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "code": 6029,
-      "name": "alreadyMinted",
-      "msg": "Trophies already minted for this game"
-    }
-  ],
-  "types": [
-    {
-      "name": "battleState",
+      "name": "cardStats",
+      "docs": [
+        "Level 1 stats of a card. Elixir cost is in whole elixir, timings are in ticks and",
+        "distances in tiles."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "gameId",
-            "type": "u64"
+            "name": "cardId",
+            "type": "u8"
           },
           {
-            "name": "players",
+            "name": "cost",
+            "type": "u8"
+          },
+          {
+            "name": "health",
+            "type": "i32"
+          },
+          {
+            "name": "damage",
+            "type": "i32"
+          },
+          {
+            "name": "hitSpeed",
+            "type": "u8"
+          },
+          {
+            "name": "range",
+            "docs": [
+              "Attack range, or the effect radius for spells"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "speed",
+            "type": "u8"
+          },
+          {
+            "name": "targets",
             "type": {
-              "array": [
-                "pubkey",
-                2
-              ]
+              "defined": {
+                "name": "targetType"
+              }
             }
           },
           {
-            "name": "status",
+            "name": "flying",
+            "docs": [
+              "Flying units can only be hit by `TargetType::Air` attackers and towers"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "rarity",
             "type": {
               "defined": {
-                "name": "gameStatus"
+                "name": "rarity"
               }
             }
           },
           {
-            "name": "tickCount",
-            "type": "u64"
+            "name": "kind",
+            "type": {
+              "defined": {
+                "name": "cardKind"
+              }
+            }
           },
           {
-            "name": "elixir",
+            "name": "lifetime",
+            "docs": [
+              "Ticks a building stands before decaying away (0 for other kinds)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "unitCount",
+            "docs": [
+              "Units spawned per deploy; the first `unit_count` formation slots are used"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "formation",
             "type": {
               "array": [
-                "u64",
-                2
+                {
+                  "defined": {
+                    "name": "formationOffset"
+                  }
+                },
+                8
               ]
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "clan",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "leader",
+            "type": "pubkey"
+          },
+          {
+            "name": "memberCount",
+            "type": "u8"
+          },
+          {
+            "name": "minTrophies",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "clanMember",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "clan",
+            "type": "pubkey"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "role",
+            "type": {
+              "defined": {
+                "name": "clanRole"
+              }
+            }
+          },
+          {
+            "name": "lastRequestTime",
+            "type": "i64"
+          },
+          {
+            "name": "donationsGiven",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "clanRole",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "member"
+          },
+          {
+            "name": "elder"
+          },
+          {
+            "name": "coLeader"
+          },
+          {
+            "name": "leader"
+          }
+        ]
+      }
+    },
+    {
+      "name": "deckCard",
+      "docs": [
+        "A deck slot as fielded in a battle. `card_id` 0 is an empty slot."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cardId",
+            "type": "u8"
+          },
+          {
+            "name": "level",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "donationRequest",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "clan",
+            "type": "pubkey"
           },
           {
-            "name": "towers",
-            "type": {
-              "array": [
-                {
-                  "defined": {
-                    "name": "tower"
-                  }
-                },
-                6
-              ]
-            }
+            "name": "player",
+            "type": "pubkey"
           },
           {
-            "name": "entities",
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "entity"
-                }
-              }
-            }
+            "name": "cardId",
+            "type": "u8"
           },
           {
-            "name": "winner",
-            "type": {
-              "option": "u8"
-            }
+            "name": "amountNeeded",
+            "type": "u8"
+          },
+          {
+            "name": "amountFilled",
+            "type": "u8"
           },
           {
-            "name": "trophiesMinted",
+            "name": "isActive",
             "type": "bool"
           },
           {
-            "name": "towersDestroyed",
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "elixirSchedule",
+      "docs": [
+        "Elixir regeneration schedule. Double elixir covers the last `double_elixir_seconds`",
+        "of regulation; overtime uses its own multiplier."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "msPerElixir",
             "docs": [
-              "How many of the enemy's towers each player has destroyed (princess only)"
+              "Milliseconds to regenerate one elixir at the normal rate"
             ],
-            "type": {
-              "array": [
-                "u8",
-                2
-              ]
-            }
+            "type": "u32"
           },
           {
-            "name": "damageDealt",
-            "docs": [
-              "Total HP damage dealt to enemy towers by each player"
-            ],
-            "type": {
-              "array": [
-                "u64",
-                2
-              ]
-            }
+            "name": "doubleElixirSeconds",
+            "type": "u32"
           },
           {
-            "name": "lastUpdateTime",
-            "type": "i64"
+            "name": "doubleMultiplier",
+            "type": "u8"
+          },
+          {
+            "name": "overtimeMultiplier",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "battleState2v2",
+      "name": "endReason",
+      "docs": [
+        "How a completed battle was decided."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "played"
+          },
+          {
+            "name": "forfeit"
+          },
+          {
+            "name": "timeout"
+          }
+        ]
+      }
+    },
+    {
+      "name": "entity",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "gameId",
-            "type": "u64"
+            "name": "id",
+            "type": "u32"
           },
           {
-            "name": "players",
-            "type": {
-              "array": [
-                "pubkey",
-                4
-              ]
-            }
+            "name": "ownerIdx",
+            "type": "u8"
           },
           {
-            "name": "status",
-            "type": {
-              "defined": {
-                "name": "gameStatus"
-              }
-            }
+            "name": "cardId",
+            "type": "u8"
           },
           {
-            "name": "tickCount",
-            "type": "u64"
+            "name": "x",
+            "type": "i32"
           },
           {
-            "name": "elixir",
-            "type": {
-              "array": [
-                "u64",
-                4
-              ]
-            }
+            "name": "y",
+            "type": "i32"
           },
           {
-            "name": "towers",
-            "type": {
-              "array": [
-                {
-                  "defined": {
-                    "name": "tower"
-                  }
-                },
-                6
-              ]
-            }
+            "name": "health",
+            "type": "i32"
           },
           {
-            "name": "entities",
+            "name": "damage",
+            "type": "i32"
+          },
+          {
+            "name": "state",
             "type": {
-              "vec": {
-                "defined": {
-                  "name": "entity"
-                }
+              "defined": {
+                "name": "entityState"
               }
             }
           },
           {
-            "name": "winner",
+            "name": "targetId",
             "type": {
-              "option": "u8"
+              "option": "u32"
             }
           },
           {
-            "name": "trophiesMinted",
-            "type": {
-              "array": [
-                "bool",
-                4
-              ]
-            }
+            "name": "speed",
+            "docs": [
+              "Tiles moved per tick"
+            ],
+            "type": "u8"
           },
           {
-            "name": "towersDestroyed",
+            "name": "range",
             "docs": [
-              "How many of the enemy's towers each team has destroyed (princess only)"
+              "Attack range in tiles"
             ],
-            "type": {
-              "array": [
-                "u8",
-                2
-              ]
-            }
+            "type": "u8"
           },
           {
-            "name": "damageDealt",
+            "name": "hitSpeed",
             "docs": [
-              "Total HP damage dealt to enemy towers by each team"
+              "Ticks between attacks"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "cooldown",
+            "docs": [
+              "Ticks until the next attack is ready"
             ],
+            "type": "u8"
+          },
+          {
+            "name": "targets",
             "type": {
-              "array": [
-                "u64",
-                2
-              ]
+              "defined": {
+                "name": "targetType"
+              }
             }
           },
           {
-            "name": "lastUpdateTime",
-            "type": "i64"
+            "name": "flying",
+            "type": "bool"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": {
+                "name": "cardKind"
+              }
+            }
+          },
+          {
+            "name": "lifetime",
+            "docs": [
+              "Ticks left before a building decays away (0 = no lifetime)"
+            ],
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "cardMintState",
+      "name": "entityState",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "cardId",
-            "type": "u8"
+            "name": "idle"
           },
           {
-            "name": "level",
-            "type": "u8"
+            "name": "moving"
           },
           {
-            "name": "xp",
-            "type": "u16"
+            "name": "attacking"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "dead"
           }
         ]
       }
     },
     {
-      "name": "cardProgress",
+      "name": "formationOffset",
+      "docs": [
+        "Position of one unit relative to the deploy tile, from side 0's point of view."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "cardId",
-            "type": "u8"
-          },
-          {
-            "name": "level",
-            "type": "u8"
-          },
-          {
-            "name": "xp",
-            "type": "u16"
+            "name": "dx",
+            "type": "i8"
           },
           {
-            "name": "amount",
-            "type": "u32"
+            "name": "dy",
+            "type": "i8"
           }
         ]
       }
     },
     {
-      "name": "clan",
+      "name": "gameConfig",
+      "docs": [
+        "Global game settings, seeded by [b\"config\"]. Battles copy what they need at creation",
+        "so changing the config never affects a match in progress."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "name",
-            "type": "string"
+            "name": "admin",
+            "type": "pubkey"
           },
           {
-            "name": "leader",
-            "type": "pubkey"
+            "name": "regulationSeconds",
+            "type": "u32"
           },
           {
-            "name": "memberCount",
-            "type": "u8"
+            "name": "overtimeSeconds",
+            "type": "u32"
           },
           {
-            "name": "minTrophies",
+            "name": "afkTimeoutSeconds",
+            "docs": [
+              "Seconds a side may go without deploying before the other can claim the win"
+            ],
             "type": "u32"
           },
+          {
+            "name": "elixirSchedule",
+            "type": {
+              "defined": {
+                "name": "elixirSchedule"
+              }
+            }
+          },
+          {
+            "name": "arena",
+            "type": {
+              "defined": {
+                "name": "arenaMap"
+              }
+            }
+          },
           {
             "name": "bump",
             "type": "u8"
@@ -2968,89 +6246,110 @@ export type GameCore = {
       }
     },
     {
-      "name": "clanMember",
+      "name": "gameCounter",
+      "docs": [
+        "Hands out battle ids, seeded by [b\"game_counter\"]. One counter serves both 1v1 and",
+        "2v2 battles, so an id is unique across modes. Creating a game takes `next_game_id`,",
+        "so clients read it to derive the new battle's PDA (see `CreateGame`)."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "clan",
-            "type": "pubkey"
-          },
-          {
-            "name": "player",
-            "type": "pubkey"
-          },
-          {
-            "name": "role",
-            "type": {
-              "defined": {
-                "name": "clanRole"
-              }
-            }
+            "name": "nextGameId",
+            "type": "u64"
           },
           {
-            "name": "lastRequestTime",
-            "type": "i64"
-          },
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gameRef",
+      "docs": [
+        "Where to find a battle: `team_size` 1 is seeded [b\"battle\", id], 2 is [b\"battle2v2\", id]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "donationsGiven",
-            "type": "u32"
+            "name": "gameId",
+            "type": "u64"
           },
           {
-            "name": "bump",
+            "name": "teamSize",
             "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "clanRole",
+      "name": "gameStatus",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "member"
-          },
-          {
-            "name": "elder"
+            "name": "waiting"
           },
           {
-            "name": "coLeader"
+            "name": "active"
           },
           {
-            "name": "leader"
+            "name": "completed"
           }
         ]
       }
     },
     {
-      "name": "donationRequest",
+      "name": "levelRule",
+      "docs": [
+        "How card levels are adjusted when decks are snapshotted into a battle."
+      ],
       "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "clan",
-            "type": "pubkey"
-          },
+        "kind": "enum",
+        "variants": [
           {
-            "name": "player",
-            "type": "pubkey"
+            "name": "standard"
           },
           {
-            "name": "cardId",
-            "type": "u8"
+            "name": "cap",
+            "fields": [
+              "u8"
+            ]
           },
           {
-            "name": "amountNeeded",
-            "type": "u8"
-          },
+            "name": "fixed",
+            "fields": [
+              "u8"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "matchQueue",
+      "docs": [
+        "Players waiting for a ranked match, seeded by [b\"match_queue\", &[team_size]]: team",
+        "size 1 for 1v1, 2 for 2v2. Entries are kept in the order they joined."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "amountFilled",
+            "name": "teamSize",
             "type": "u8"
           },
           {
-            "name": "isActive",
-            "type": "bool"
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "queueEntry"
+                }
+              }
+            }
           },
           {
             "name": "bump",
@@ -3060,131 +6359,194 @@ export type GameCore = {
       }
     },
     {
-      "name": "entity",
+      "name": "playerProfile",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "id",
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "mmr",
             "type": "u32"
           },
           {
-            "name": "ownerIdx",
-            "type": "u8"
+            "name": "deck",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
           },
           {
-            "name": "cardId",
-            "type": "u8"
+            "name": "inventory",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "cardProgress"
+                }
+              }
+            }
           },
           {
-            "name": "x",
-            "type": "i32"
+            "name": "username",
+            "type": "string"
           },
           {
-            "name": "y",
-            "type": "i32"
+            "name": "trophies",
+            "type": "u32"
           },
           {
-            "name": "health",
-            "type": "i32"
+            "name": "roadClaimed",
+            "docs": [
+              "Bit `i` is set once TROPHY_ROAD[i] has been claimed"
+            ],
+            "type": "u64"
           },
           {
-            "name": "damage",
-            "type": "i32"
+            "name": "season",
+            "docs": [
+              "Season the fields below are tracking"
+            ],
+            "type": "u32"
           },
           {
-            "name": "state",
+            "name": "seasonBestTrophies",
+            "type": "u32"
+          },
+          {
+            "name": "bestSeason",
+            "docs": [
+              "Season with the highest best trophies so far, and that count"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "bestSeasonTrophies",
+            "type": "u32"
+          },
+          {
+            "name": "pendingSeasonReward",
+            "docs": [
+              "End-of-season tokens earned but not yet claimed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdGames",
+            "docs": [
+              "Lobbies this player created, newest last"
+            ],
             "type": {
-              "defined": {
-                "name": "entityState"
+              "vec": {
+                "defined": {
+                  "name": "gameRef"
+                }
               }
             }
           },
           {
-            "name": "targetId",
+            "name": "activeGame",
+            "docs": [
+              "Battle this player was last seated in. Checked, and cleared once that battle is",
+              "over, when they next join a match queue."
+            ],
             "type": {
-              "option": "u32"
+              "option": {
+                "defined": {
+                  "name": "gameRef"
+                }
+              }
             }
           }
         ]
       }
     },
     {
-      "name": "entityState",
+      "name": "queueEntry",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "idle"
+            "name": "player",
+            "type": "pubkey"
           },
           {
-            "name": "moving"
+            "name": "mmr",
+            "type": "u32"
           },
           {
-            "name": "attacking"
+            "name": "deck",
+            "docs": [
+              "Deck and levels as they were when the player queued"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "deckCard"
+                  }
+                },
+                8
+              ]
+            }
           },
           {
-            "name": "dead"
+            "name": "enqueuedAt",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "gameStatus",
+      "name": "rarity",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "waiting"
+            "name": "common"
           },
           {
-            "name": "active"
+            "name": "rare"
           },
           {
-            "name": "completed"
+            "name": "epic"
+          },
+          {
+            "name": "legendary"
           }
         ]
       }
     },
     {
-      "name": "playerProfile",
+      "name": "season",
+      "docs": [
+        "The current ladder season, seeded by [b\"season\"]. Rolled over permissionlessly once",
+        "`ends_at` passes; player profiles catch up lazily."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
-            "type": "pubkey"
-          },
-          {
-            "name": "mmr",
+            "name": "number",
+            "docs": [
+              "Starts at 1; profiles at 0 have not played a season yet"
+            ],
             "type": "u32"
           },
           {
-            "name": "deck",
-            "type": {
-              "array": [
-                "u8",
-                8
-              ]
-            }
-          },
-          {
-            "name": "inventory",
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "cardProgress"
-                }
-              }
-            }
+            "name": "startedAt",
+            "type": "i64"
           },
           {
-            "name": "username",
-            "type": "string"
+            "name": "endsAt",
+            "type": "i64"
           },
           {
-            "name": "trophies",
-            "type": "u32"
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
@@ -3213,6 +6575,23 @@ export type GameCore = {
         ]
       }
     },
+    {
+      "name": "targetType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "ground"
+          },
+          {
+            "name": "air"
+          },
+          {
+            "name": "buildings"
+          }
+        ]
+      }
+    },
     {
       "name": "tower",
       "type": {
@@ -3240,6 +6619,22 @@ export type GameCore = {
           }
         ]
       }
+    },
+    {
+      "name": "towerSite",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "x",
+            "type": "i32"
+          },
+          {
+            "name": "y",
+            "type": "i32"
+          }
+        ]
+      }
     }
   ]
 };
@@ -212,7 +212,11 @@ export const TestProgramPage = () => {
                 </Section>
 
                 <Section title="Game Lifecycle (1v1)">
-                    <button onClick={() => handleAction("createGame", () => gameProgram.createGame(new BN(gameId)))}>Create Game</button>
+                    <button onClick={() => handleAction("createGame", async () => {
+                        const { tx, gameId: id } = await gameProgram.createGame();
+                        setGameId(id.toString());
+                        return tx;
+                    })}>Create Game</button>
                     <button onClick={() => handleAction("joinGame", () => gameProgram.joinGame(new BN(gameId)))}>Join Game</button>
                     <button onClick={() => handleAction("delegateGame", () => gameProgram.delegateGame(new BN(gameId)))}>Delegate Game</button>
                     <div style={{ width: "100%", display: "flex", gap: "10px", alignItems: "center", marginTop: "10px" }}>
@@ -220,18 +224,22 @@ export const TestProgramPage = () => {
                         <button onClick={() => handleAction("endGame", () => gameProgram.endGame(new BN(gameId), parseInt(winnerIdx)))} style={{ background: "#c62828" }}>End Game (ER)</button>
                         <button onClick={() => handleAction("commitBattle", () => gameProgram.commitBattle(new BN(gameId)))} style={{ background: "#7b1fa2" }}>Commit Battle (L1)</button>
                     </div>
-                    <button onClick={() => handleAction("mintTrophies", () => gameProgram.mintTrophies(new BN(gameId), new PublicKey(mintAddr)))} disabled={!mintAddr}>Mint Trophies</button>
+                    <button onClick={() => handleAction("settleBattle", () => gameProgram.settleBattle(new BN(gameId), new PublicKey(mintAddr)))} disabled={!mintAddr}>Settle Battle</button>
                 </Section>
 
                 <Section title="Game Lifecycle (2v2)">
-                    <button onClick={() => handleAction("createGame2v2", () => gameProgram.createGame2v2(new BN(gameId)))} style={{ border: "1px solid #4CAF50" }}>Create Game 2v2</button>
+                    <button onClick={() => handleAction("createGame2v2", async () => {
+                        const { tx, gameId: id } = await gameProgram.createGame2v2();
+                        setGameId(id.toString());
+                        return tx;
+                    })} style={{ border: "1px solid #4CAF50" }}>Create Game 2v2</button>
                     <button onClick={() => handleAction("joinGame2v2", () => gameProgram.joinGame2v2(new BN(gameId)))} style={{ border: "1px solid #4CAF50" }}>Join Game 2v2</button>
                     <button onClick={() => handleAction("delegateGame2v2", () => gameProgram.delegateGame2v2(new BN(gameId)))} style={{ border: "1px solid #4CAF50" }}>Delegate Game 2v2</button>
                     <div style={{ width: "100%", display: "flex", gap: "10px", alignItems: "center", marginTop: "10px" }}>
                         <button onClick={() => handleAction("endGame2v2", () => gameProgram.endGame2v2(new BN(gameId), parseInt(winnerIdx)))} style={{ background: "#d32f2f" }}>End Game 2v2 (ER)</button>
                         <button onClick={() => handleAction("commitBattle2v2", () => gameProgram.commitBattle2v2(new BN(gameId)))} style={{ background: "#9c27b0" }}>Commit Battle 2v2 (L1)</button>
                     </div>
-                    <button onClick={() => handleAction("mintTrophies2v2", () => gameProgram.mintTrophies2v2(new BN(gameId), new PublicKey(mintAddr)))} disabled={!mintAddr} style={{ border: "1px solid #FFD700" }}>Mint Trophies 2v2</button>
+                    <button onClick={() => handleAction("settleBattle2v2", () => gameProgram.settleBattle2v2(new BN(gameId), new PublicKey(mintAddr)))} disabled={!mintAddr} style={{ border: "1px solid #FFD700" }}>Settle Battle 2v2</button>
                 </Section>
 
                 <Section title="Combat (ER)">
@@ -29,7 +29,7 @@ export const VictoryScreen: React.FC<VictoryScreenProps> = ({
     is2v2 = false
 }) => {
     const navigate = useNavigate();
-    const { mintTrophies, endGame, mintTrophies2v2, endGame2v2, isLoading, error } = useGameProgram();
+    const { settleBattle, endGame, settleBattle2v2, endGame2v2, isLoading, error } = useGameProgram();
     const [countdown, setCountdown] = React.useState(8);
 
     // New states for the multi-step ending process
@@ -69,25 +69,25 @@ export const VictoryScreen: React.FC<VictoryScreenProps> = ({
                 setHasEndedGame(true);
                 setIsEndingGame(false);
 
-                // Step 2: Mint Trophies (Base Layer)
+                // Step 2: Settle Battle (Base Layer): trophies for everyone, tokens for the winners
                 // Small delay to ensure Base node has seen the commitment
                 await new Promise(r => setTimeout(r, 2000));
 
-                let mintRetries = 3;
-                while (mintRetries > 0) {
+                let settleRetries = 3;
+                while (settleRetries > 0) {
                     try {
-                        console.log(`[VictorySequence] Minting Trophies, Attempt: ${4 - mintRetries}`);
+                        console.log(`[VictorySequence] Settling Battle, Attempt: ${4 - settleRetries}`);
                         if (is2v2) {
-                            await mintTrophies2v2(new BN(gameId), MINT_CONFIG.GOLD);
+                            await settleBattle2v2(new BN(gameId), MINT_CONFIG.GOLD);
                         } else {
-                            await mintTrophies(new BN(gameId), MINT_CONFIG.GOLD);
+                            await settleBattle(new BN(gameId), MINT_CONFIG.GOLD);
                         }
-                        toast.success('🏆 Trophies Minted! 🏆');
+                        toast.success('🏆 Rewards Settled! 🏆');
                         break;
                     } catch (err: any) {
-                        console.warn(`[VictorySequence] Mint failed: ${err.message}`);
-                        mintRetries--;
-                        if (mintRetries > 0) {
+                        console.warn(`[VictorySequence] Settle failed: ${err.message}`);
+                        settleRetries--;
+                        if (settleRetries > 0) {
                             await new Promise(r => setTimeout(r, 4000));
                         } else {
                             throw err; // Out of retries
@@ -101,7 +101,7 @@ export const VictoryScreen: React.FC<VictoryScreenProps> = ({
         };
 
         runSequence();
-    }, [winner, gameId, role, endGame, mintTrophies]);
+    }, [winner, gameId, role, endGame, settleBattle]);
 
     const getTitle = () => {
         if (winner === 'player') return 'VICTORY!';
@@ -211,9 +211,9 @@ export const VictoryScreen: React.FC<VictoryScreenProps> = ({
                                     )}
                                 </div>
 
-                                {/* Step 2: Minting */}
+                                {/* Step 2: Settling */}
                                 <div className="flex items-center justify-between text-sm">
-                                    <span className="text-gray-300">2. Claiming Trophies</span>
+                                    <span className="text-gray-300">2. Settling Rewards</span>
                                     {isLoading && hasEndedGame ? (
                                         <div className="w-4 h-4 border-2 border-orange-400 border-t-transparent rounded-full animate-spin"></div>
                                     ) : hasEndedGame && error ? (
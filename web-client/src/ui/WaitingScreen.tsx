@@ -92,23 +92,21 @@ export const WaitingScreen: React.FC = () => {
         if (!socket) return;
         setView('create');
 
-        // Generate a random 6-digit ID on the frontend
-        const randomId = String(Math.floor(100000 + Math.random() * 900000));
-        setCreatedGameId(randomId);
         setStatus('Creating game on-chain...');
 
         try {
-            const gameIdBn = new BN(randomId);
-            const p = createGame(gameIdBn);
+            // The program hands out the game id; we learn it once the game exists
+            const p = createGame();
             toast.promise(p, {
                 loading: 'Creating game on-chain...',
                 success: 'Game created! Waiting for opponent...',
                 error: (e) => `Failed: ${e.message}`,
             });
-            await p;
+            const gameId = (await p).gameId.toString();
+            setCreatedGameId(gameId);
 
             // Only AFTER successful on-chain creation do we open the server room
-            socket.emit('create-room', { gameId: randomId });
+            socket.emit('create-room', { gameId });
             setStatus('Waiting for opponent to join...');
         } catch (err: any) {
             setStatus(`Error: ${err.message}`);